@@ -0,0 +1,365 @@
+# Bot-Antworten und Benachrichtigungen, Deutsch.
+
+## Shared
+
+error-no-user = Benutzer konnte nicht ermittelt werden.
+error-server-only = Dieser Befehl funktioniert nur in Textkanälen eines Servers.
+invalid-channel-name = Ungültiger Kanal. Gib einen Twitch-Login (4-25 Buchstaben, Ziffern oder Unterstriche), einen twitch.tv-Link oder eine Broadcaster-ID an.
+invalid-custom-message = Ungültige Nachricht: { $error }
+twitch-channel-not-found = Twitch-Kanal '{ $channel }' nicht gefunden.
+twitch-lookup-failed = Der Kanal konnte nicht über die Twitch-API geprüft werden.
+stream-not-in-channel = Stream { $channel } ist in diesem Kanal nicht eingerichtet.
+stream-not-in-server = Kanal '{ $channel }' wird auf diesem Server nicht überwacht.
+stream-managed-by-team = Kanal '{ $channel }' wird angekündigt, weil dieser Server dem Team **{ $team }** folgt. Nutze !removeteam { $team }, um das zu beenden.
+role-not-found = Rolle '{ $role }' gibt es auf diesem Server nicht.
+role-deleted = { $role } (Rolle existiert nicht mehr)
+text-channel-not-found = '{ $channel }' ist kein Textkanal auf diesem Server.
+quota-user-reached = Du hast das Limit von { $max } Streams auf diesem Server erreicht.
+quota-server-reached = Dieser Server hat sein Limit von { $max } Streams erreicht.
+quota-channel-reached = Der Zielkanal hat sein Limit von { $max } Streams erreicht.
+args-usage = Verwendung: { $usage }
+args-missing-argument = { $name } fehlt.
+args-missing-value = Die Option --{ $name } braucht einen Wert.
+args-unknown-option = Unbekannte Option --{ $name }.
+args-too-many = Zu viele Argumente. Setze Text mit Leerzeichen in "doppelte Anführungszeichen".
+args-unclosed-quote = Einem Anführungszeichen fehlt das schließende ".
+permission-denied = Für diesen Befehl brauchst du „Kanal verwalten“, „Server verwalten“ oder die Stream-Manager-Rolle.
+value-none = keine
+value-any = alle
+value-off = aus
+value-yes = ja
+value-no = nein
+value-server-default = Server-Standard
+
+## Notifications
+
+notification-default = { $name } ist jetzt live! { $url }
+embed-live = { $name } ist live
+embed-playing = Spielt **{ $game }**
+embed-viewers = { $count ->
+    [one] { $count } Zuschauer
+   *[other] { $count } Zuschauer
+}
+quiet-summary-title = Während der Ruhezeit live gegangen:
+
+## Templates
+
+template-unknown-placeholder = Unbekannter Platzhalter `{ $placeholder }`. Verfügbar: { $available }
+template-unclosed-placeholder = Eine `{"{"}` wird nie geschlossen. Verwende `{"{{"}` für eine geschweifte Klammer.
+template-unclosed-section = Dem Abschnitt `{ $open }` fehlt das `{ $close }`.
+template-unexpected-section-end = Zu `{ $close }` gibt es kein passendes `{ $open }`.
+template-sample-title = Beispiel-Streamtitel
+
+## Quiet hours
+
+quiet-invalid-start = Ungültige Startzeit '{ $value }', erwartet wird HH:MM.
+quiet-invalid-end = Ungültige Endzeit '{ $value }', erwartet wird HH:MM.
+quiet-same-start-end = Start- und Endzeit müssen sich unterscheiden.
+quiet-unknown-timezone = Unbekannte Zeitzone '{ $value }', z. B. Europe/Berlin.
+quiet-unknown-mode = Unbekannter Modus '{ $value }', verwende drop, silent oder queue.
+
+## !addstream
+
+addstream-added = Kanal hinzugefügt: { $channel }
+addstream-added-custom = Kanal hinzugefügt: { $channel } (mit eigener Nachricht)
+addstream-added-elsewhere = , Benachrichtigungen gehen an <#{ $target }>
+addstream-duplicate = Dieser Kanal ist auf diesem Server bereits eingerichtet.
+addstream-failed = Kanal konnte nicht hinzugefügt werden.
+
+## !liststreams
+
+liststreams-empty = Für diesen Kanal sind keine Streams eingerichtet.
+liststreams-empty-server = Auf diesem Server sind keine Streams eingerichtet.
+liststreams-title = Streams in diesem Kanal ({ $count }):
+liststreams-title-all = Streams auf diesem Server ({ $count }):
+liststreams-entry = **{ $channel }**, hinzugefügt von { $user } am { $date }
+liststreams-live = 🔴 live
+liststreams-games = Spiele: { $games }
+liststreams-keywords = Stichwörter: { $keywords }
+liststreams-page = Seite { $page } von { $pages }.
+liststreams-next-page = Mit { $command } geht es zur nächsten.
+liststreams-no-page = { $pages ->
+    [one] Es gibt nur eine Seite.
+   *[other] Es gibt nur { $pages } Seiten.
+}
+liststreams-custom-message = eigene Nachricht
+liststreams-invalid-message = ⚠ ungültige eigene Nachricht, die Standardnachricht wird gesendet
+liststreams-disabled = deaktiviert
+liststreams-paused = pausiert
+
+## !live
+
+live-title = Gerade live ({ $count }):
+live-details = { $game } · seit { $uptime } live · { $viewers }
+live-no-game = Keine Kategorie
+live-more = … und { $count } weitere
+live-nobody = Gerade ist niemand live. Schau später wieder vorbei!
+live-failed = Die Livestreams konnten nicht von Twitch abgerufen werden, versuche es gleich noch einmal.
+
+## !editstream
+
+editstream-usage =
+    Verwendung: !editstream <kanalname> <feld> <wert>
+    Felder: `message <text|default>`, `games <spiel, ...|any>`, `keywords <wort, ...|any>`, `mention <@rolle|rollenname|none|default>`, `mode <text|embed|default>`, `enabled <on|off>`
+editstream-mode-usage = Verwendung: !editstream <kanalname> mode <text|embed|default>
+editstream-enabled-usage = Verwendung: !editstream <kanalname> enabled <on|off>
+editstream-not-yours = Du kannst nur Streams bearbeiten, die du hinzugefügt hast.
+editstream-updated = **{ $channel } aktualisiert:**
+    - Nachricht: { $message }
+    - Spiele: { $games }
+    - Stichwörter: { $keywords }
+    - Erwähnung: { $mention }
+    - Modus: { $mode }
+    - Aktiv: { $enabled }
+
+## !removestream
+
+removestream-removed = Kanal entfernt: { $channel }
+removestream-not-found = Stream nicht gefunden.
+removestream-not-yours = Du kannst nur Streams entfernen, die du selbst hinzugefügt hast.
+removestream-failed = Stream konnte nicht entfernt werden.
+
+## !addstreams und !removestreams
+
+bulk-channel = Kanal
+bulk-result = Ergebnis
+bulk-added = hinzugefügt
+bulk-already-present = bereits vorhanden
+bulk-not-found = nicht gefunden
+bulk-over-quota = Limit erreicht
+bulk-removed = entfernt
+bulk-not-yours = nicht deiner
+bulk-team = über Team { $team }
+bulk-added-elsewhere = Benachrichtigungen gehen an <#{ $target }>.
+bulk-more = … und { $count } weitere
+
+## !addteam und !removeteam
+
+addteam-invalid = '{ $team }' ist kein gültiger Twitch-Team-Name.
+addteam-not-found = Twitch-Team '{ $team }' nicht gefunden.
+addteam-duplicate = Dem Team **{ $team }** wird auf diesem Server bereits gefolgt.
+addteam-added = Team **{ $team }** wird verfolgt, { $count ->
+    [one] 1 Mitglied hinzugefügt.
+   *[other] { $count } Mitglieder hinzugefügt.
+}
+addteam-already-tracked = Bereits einzeln verfolgt: { $members }
+removeteam-not-found = Dieser Server folgt keinem Team namens '{ $team }'.
+removeteam-not-yours = Du kannst nur Teams entfernen, die du hinzugefügt hast.
+removeteam-removed = Team **{ $team }** und { $count ->
+    [one] 1 Stream entfernt.
+   *[other] { $count } Streams entfernt.
+}
+team-sync-title = Team { $team } hat sich geändert:
+team-sync-joined = ➕ Beigetreten: { $members }
+team-sync-left = ➖ Ausgetreten: { $members }
+
+## !addcategory und !removecategory
+
+category-entry = { $game } · { $count ->
+    [one] ab 1 Zuschauer
+   *[other] ab { $count } Zuschauern
+} · { $language } → <#{ $target }>
+category-any-language = jede Sprache
+category-list-title = Verfolgte Kategorien ({ $count }):
+category-list-empty = Dieser Server verfolgt noch keine Kategorien.
+category-new-streams = { $count ->
+    [one] Neuer { $game }-Stream:
+   *[other] { $count } neue { $game }-Streams:
+}
+addcategory-invalid-viewers = '{ $value }' ist keine Zuschauerzahl.
+addcategory-invalid-language = '{ $value }' ist kein Sprachcode, nutze zwei Buchstaben wie en oder de.
+addcategory-limit = Dieser Server verfolgt bereits { $max } Kategorien, mehr sind nicht möglich.
+addcategory-not-found = Twitch-Kategorie '{ $game }' nicht gefunden. Nutze den genauen Namen oder die ID.
+addcategory-added = Neue Streams werden jetzt angekündigt in { $entry }
+addcategory-duplicate = **{ $game }** wird auf diesem Server bereits verfolgt.
+removecategory-not-found = Dieser Server verfolgt keine Kategorie namens '{ $game }'.
+removecategory-not-yours = Du kannst nur Kategorien entfernen, die du hinzugefügt hast.
+removecategory-removed = **{ $game }** wird nicht mehr angekündigt.
+
+## !movestream
+
+movestream-cannot-send = Ich kann in <#{ $target }> keine Nachrichten senden. Prüfe zuerst meine Berechtigungen dort.
+movestream-not-yours = Du kannst nur Streams verschieben, die du hinzugefügt hast.
+movestream-already-there = { $channel } benachrichtigt bereits <#{ $target }>.
+movestream-duplicate = { $channel } ist in <#{ $target }> bereits eingerichtet.
+movestream-moved = { $channel } benachrichtigt jetzt <#{ $target }>.
+movestream-failed = Stream konnte nicht verschoben werden.
+
+## !transferstream
+
+transferstream-user-not-found = { $user } ist kein Mitglied dieses Servers.
+transferstream-not-yours = Du kannst nur Streams übertragen, die du hinzugefügt hast.
+transferstream-already-owner = { $channel } gehört bereits <@{ $user }>.
+transferstream-limit-reached = <@{ $user }> hat das Maximum von { $max } Streams erreicht.
+transferstream-transferred = { $channel } gehört jetzt <@{ $user }>.
+transferstream-failed = Stream konnte nicht übertragen werden.
+
+## !streamconfig
+
+streamconfig-usage = Verwendung: !streamconfig [cooldown <minuten> | quiet <HH:MM> <HH:MM> <zeitzone> [drop|silent|queue] | quiet off | template <nachricht|default> | mention <@rolle|none> | mode <text|embed> | channel <#kanal|here|none> | language <code> | prefix <präfix|default> | managerrole <@rolle|none>]
+streamconfig-summary = **Stream-Einstellungen für diesen Server:**
+    - Neustart-Sperre: { $cooldown }
+    - Ruhezeit: { $quiet_hours }
+    - Standardnachricht: { $message }
+    - Standard-Erwähnung: { $mention }
+    - Nachrichtenmodus: { $mode }
+    - Standardkanal: { $channel }
+    - Sprache: { $language }
+    - Befehlspräfix: `{ $prefix }`
+    - Stream-Manager-Rolle: { $manager_role }
+    - { $capacity }
+streamconfig-capacity = Freie Streams: { $server_left } von { $server_max } auf diesem Server, { $channel_left } von { $channel_max } in <#{ $target }>, { $user_left } von { $user_max } für dich
+streamconfig-minutes = { $minutes } Min.
+streamconfig-minutes-default = { $minutes } Min. (Standard)
+streamconfig-builtin-message = { $message } (eingebaut)
+streamconfig-channel-unset = wo !addstream verwendet wird
+streamconfig-cooldown-usage = Verwendung: !streamconfig cooldown <minuten> (0-{ $max })
+streamconfig-cooldown-disabled = Neustart-Sperre deaktiviert.
+streamconfig-cooldown-set = Streams, die innerhalb von { $minutes } Min. nach dem Offline-Gehen wieder starten, werden nicht erneut angekündigt.
+streamconfig-quiet-usage = Verwendung: !streamconfig quiet <HH:MM> <HH:MM> <zeitzone> [drop|silent|queue] oder !streamconfig quiet off
+streamconfig-quiet-set = Ruhezeit auf { $quiet_hours } gesetzt.
+streamconfig-quiet-disabled = Ruhezeit deaktiviert.
+streamconfig-template-set = Standardnachricht auf { $message } gesetzt.
+streamconfig-template-reset = Standardnachricht auf die eingebaute zurückgesetzt.
+streamconfig-mention-set = Benachrichtigungen erwähnen { $role }, sofern ein Stream keine eigene Rolle festlegt.
+streamconfig-mention-reset = Benachrichtigungen erwähnen standardmäßig keine Rolle mehr.
+streamconfig-managerrole-set = Mitglieder mit { $role } können jetzt Streams verwalten.
+streamconfig-managerrole-reset = Stream-Manager-Rolle entfernt.
+streamconfig-managerrole-admin-only = Nur der Serverbesitzer oder Mitglieder mit „Server verwalten“ können die Stream-Manager-Rolle ändern.
+streamconfig-prefix-set = Befehle beginnen jetzt mit `{ $prefix }`. Eine Erwähnung von mir funktioniert auch.
+streamconfig-prefix-usage = Verwendung: !streamconfig prefix <präfix|default>, höchstens { $max } Zeichen ohne Leerzeichen
+streamconfig-mode-set = Benachrichtigungen werden jetzt standardmäßig als { $mode } gesendet.
+streamconfig-mode-usage = Verwendung: !streamconfig mode <text|embed>
+streamconfig-channel-set = Neue Streams benachrichtigen standardmäßig <#{ $channel }>.
+streamconfig-channel-reset = Neue Streams benachrichtigen den Kanal, in dem !addstream verwendet wird.
+streamconfig-language-set = Bot-Antworten und Benachrichtigungen auf diesem Server sind jetzt auf Deutsch.
+streamconfig-language-usage = Unbekannte Sprache. Verfügbar: { $languages }
+
+## !exportstreams und !importstreams
+
+exportstreams-empty = Auf diesem Server gibt es keine Streams zum Exportieren.
+exportstreams-exported = { $count } Streams exportiert.
+exportstreams-failed = Die Exportdatei konnte nicht hochgeladen werden.
+importstreams-managers-only = Nur Mitglieder mit „Kanäle verwalten“ oder „Server verwalten“ können Streams importieren.
+importstreams-no-file = Hänge eine JSON- oder CSV-Datei von !exportstreams an den Befehl an.
+importstreams-empty = Die Datei enthält keine Streams.
+importstreams-invalid-file = Die Datei konnte nicht gelesen werden: { $error }
+importstreams-not-text = Die Datei ist kein UTF-8-Text.
+streamfile-invalid-json = Ungültiges JSON in Zeile { $line }, Spalte { $column }.
+streamfile-unclosed-quote = Zeile { $line }: Ein Feld in Anführungszeichen wird nicht geschlossen.
+streamfile-missing-channel = Zeile 1: Die Spalte `channel` fehlt.
+streamfile-invalid-enabled = Zeile { $line }: '{ $value }' ist kein gültiger Wert für enabled, verwende true oder false.
+attachment-too-large = Die Datei ist größer als { $max } KB.
+attachment-status = Der Dateiserver hat mit HTTP { $status } geantwortet.
+attachment-request-failed = Der Dateiserver ist nicht erreichbar, versuche es später erneut.
+importstreams-invalid-mode = Unbekannter Modus '{ $mode }', verwende text oder embed.
+importstreams-duplicate = { $channel } kommt für denselben Zielkanal mehrfach vor.
+importstreams-row-error = Zeile { $row }: { $error }
+importstreams-invalid-rows = Es wurde nichts importiert, { $count } Zeilen sind fehlerhaft:
+importstreams-more = … und { $count } weitere
+importstreams-no-changes = Die Datei entspricht den aktuellen Streams, es gibt nichts zu importieren.
+importstreams-summary = **Import-Vorschau:** { $added } hinzufügen, { $updated } ändern, { $removed } entfernen, { $unchanged } unverändert
+importstreams-confirm = Antworte innerhalb von { $seconds } Sekunden mit `ja`, um die Änderungen zu übernehmen.
+importstreams-timed-out = Keine Antwort, der Import wurde abgebrochen.
+importstreams-cancelled = Import abgebrochen.
+importstreams-failed = Der Import ist fehlgeschlagen, es wurde nichts geändert.
+importstreams-applied = Import abgeschlossen: { $added } hinzugefügt, { $updated } geändert, { $removed } entfernt.
+
+## !streamquota
+
+streamquota-usage = Verwendung: !streamquota <server_id|here> [user|server|channel <limit|default>]
+streamquota-operator-only = Nur der Bot-Betreiber kann Stream-Limits ändern.
+streamquota-unknown-server = Ich bin auf keinem Server mit der ID { $server }.
+streamquota-default = { $max } (Standard)
+streamquota-summary = **Stream-Limits für { $server }:**
+    - Pro Mitglied: { $user }
+    - Pro Server: { $server_max } ({ $used } belegt)
+    - Pro Kanal: { $channel }
+
+## !streambotstatus
+
+streambotstatus-title-global = Bot-Status (alle Server)
+streambotstatus-title-server = Bot-Status für { $server }
+streambotstatus-eventsub = EventSub: { $status }
+streambotstatus-connected = verbunden, Sitzung vor { $age } gestartet
+streambotstatus-disconnected = ⚠️ getrennt, verbinde neu
+streambotstatus-keepalive = Letztes Keepalive: { $time }
+streambotstatus-ago = vor { $age }
+streambotstatus-never = noch nie
+streambotstatus-subscriptions = Abonnements: { $active } aktiv für { $tracked } beobachtete Kanäle
+streambotstatus-subscriptions-unknown = Abonnements: unbekannt (Twitch hat nicht geantwortet) für { $tracked } beobachtete Kanäle
+streambotstatus-token-valid = Twitch-Token: gültig, läuft in { $expires } ab
+streambotstatus-token-valid-forever = Twitch-Token: gültig, läuft nicht ab
+streambotstatus-token-invalid = Twitch-Token: ⚠️ ungültig oder abgelaufen
+streambotstatus-token-unknown = Twitch-Token: unbekannt (Twitch hat nicht geantwortet)
+streambotstatus-outbox = Postausgang: { $pending } ausstehend, { $failed } fehlgeschlagen
+streambotstatus-uptime = Laufzeit: { $uptime } · Version { $version }
+streambotstatus-admins-only = Nur der Serverbesitzer und Mitglieder mit „Server verwalten“ können den Bot-Status sehen.
+
+## !teststream
+
+teststream-usage = Verwendung: !teststream <kanalname> [send]
+teststream-source-live = Live-Daten
+teststream-source-sample = Beispieldaten, der Streamer ist offline
+teststream-invalid-message = Die Nachricht für diesen Stream ist ungültig: { $error }
+teststream-preview-title = Vorschau für { $channel } in <#{ $target }>
+teststream-quiet-active = Gerade ist Ruhezeit ({ $quiet_hours }).
+teststream-send-hint = Mit `!teststream { $channel } send` wird sie in <#{ $target }> gepostet.
+teststream-sent = Testbenachrichtigung an <#{ $target }> gesendet.
+teststream-sent-reenabled = Testbenachrichtigung an <#{ $target }> gesendet. Benachrichtigungen für { $channel } sind wieder aktiv.
+teststream-cannot-post = Der Bot kann in <#{ $target }> nicht posten. Prüfe, ob er den Kanal sehen und dort Nachrichten senden darf.
+teststream-rate-limited = Von Stoat ausgebremst, versuche es gleich noch einmal.
+teststream-send-failed = Testbenachrichtigung konnte nicht gesendet werden.
+
+## !helpstream
+
+help-title = Stream-Benachrichtigungsbot
+help-topics = Hilfethemen:
+help-topic-streams = Streams hinzufügen, bearbeiten und entfernen
+help-topic-teams = Twitch-Teams und Kategorien folgen
+help-topic-lists = Streams auflisten, exportieren und importieren
+help-topic-config = Servereinstellungen und eigene Nachrichten
+help-unknown-topic = Es gibt kein Hilfethema '{ $topic }'.
+help-addstream = Einen Twitch-Kanal überwachen
+help-addstream-message = Mit eigener Benachrichtigung überwachen
+help-addstream-options = Erwähnung, Kategoriefilter oder Embed-Modus direkt festlegen
+help-addstreams = Mehrere Kanäle auf einmal hinzufügen, getrennt durch Leerzeichen oder Kommas
+help-editstream-message = Benachrichtigungstext ändern
+help-editstream-games = Nur für diese Kategorien benachrichtigen
+help-editstream-keywords = Nur benachrichtigen, wenn der Titel eines dieser Wörter enthält
+help-editstream-mention = Eine Rolle in der Benachrichtigung erwähnen
+help-editstream-mode = Nachrichtenmodus des Servers überschreiben
+help-editstream-enabled = Benachrichtigungen pausieren oder fortsetzen
+help-removestream = Einen Kanal nicht mehr überwachen
+help-removestreams = Mehrere Kanäle auf einmal entfernen
+help-addteam = Alle Mitglieder eines Twitch-Teams verfolgen, inklusive Änderungen
+help-removeteam = Ein Twitch-Team und seine Mitglieder nicht mehr verfolgen
+help-addcategory = Neue Streams einer Kategorie ankündigen, optional ab einer Zuschauerzahl oder in einer Sprache
+help-addcategory-list = Die verfolgten Kategorien dieses Servers anzeigen
+help-removecategory = Eine Kategorie nicht mehr ankündigen
+help-movestream = Benachrichtigungen eines Streams in einem anderen Kanal posten
+help-transferstream = Einen Stream an ein anderes Mitglied übergeben
+help-exportstreams = Alle Streams dieses Servers als JSON- oder CSV-Datei hochladen
+help-importstreams = Streams aus einer angehängten Datei importieren, vorher mit Vorschau; --replace entfernt auch Streams, die in der Datei fehlen
+help-liststreams = Überwachte Kanäle in diesem Kanal anzeigen, seitenweise
+help-liststreams-all = Alle überwachten Kanäle des Servers anzeigen, nach Zielkanal gruppiert
+help-live = Zeigen, wer gerade live ist, mit Titel, Spiel, Laufzeit und Zuschauern
+help-streambotstatus = Verbindung, Token und Warteschlange des Bots prüfen (Server-Admins)
+help-teststream = Vorschau der Benachrichtigung für einen Kanal
+help-teststream-send = Eine Testbenachrichtigung im Zielkanal posten
+help-streamconfig = Stream-Einstellungen des Servers anzeigen
+help-streamconfig-cooldown = Streams, die innerhalb dieser Zeit neu starten, nicht erneut ankündigen
+help-streamconfig-quiet = Ruhezeit festlegen
+help-streamconfig-quiet-off = Ruhezeit deaktivieren
+help-streamconfig-template = Standardnachricht für Streams ohne eigene
+help-streamconfig-mention = Standardmäßig erwähnte Rolle
+help-streamconfig-mode = Nur Text posten oder eine Karte mit Stream-Details anhängen
+help-streamconfig-channel = Standardkanal für neue Streams
+help-streamconfig-language = Sprache für Antworten und Benachrichtigungen (en, de, pt-BR)
+help-streamconfig-managerrole = Mitglieder mit dieser Rolle dürfen Streams verwalten
+help-streamconfig-prefix = Befehlspräfix ändern, erwähne mich, falls du es vergisst
+help-custom-messages = Eigene Nachrichten:
+help-placeholders = Platzhalter: { $placeholders }
+help-sections = Text in { $section } wird nur angezeigt, wenn das Feld gesetzt ist. Verwende { $braces } für geschweifte Klammern.
+help-example = Beispiel:
+help-example-message = { $name } ist live: { $title }
@@ -0,0 +1,366 @@
+# Bot replies and notifications, English.
+# Other catalogs fall back to this one for missing messages.
+
+## Shared
+
+error-no-user = Unable to identify user.
+error-server-only = This command only works in server text channels.
+invalid-channel-name = Invalid channel. Use a Twitch login (4-25 letters, digits or underscores), a twitch.tv link or a broadcaster ID.
+invalid-custom-message = Invalid custom message: { $error }
+twitch-channel-not-found = Twitch channel '{ $channel }' not found.
+twitch-lookup-failed = Failed to validate channel with Twitch API.
+stream-not-in-channel = Stream { $channel } not found in this channel.
+stream-not-in-server = Channel '{ $channel }' is not monitored in this server.
+stream-managed-by-team = Channel '{ $channel }' is announced because this server follows the team **{ $team }**. Use !removeteam { $team } to stop it.
+role-not-found = Role '{ $role }' not found in this server.
+role-deleted = { $role } (role no longer exists)
+text-channel-not-found = Channel '{ $channel }' is not a text channel in this server.
+quota-user-reached = You have reached the limit of { $max } streams in this server.
+quota-server-reached = This server has reached its limit of { $max } streams.
+quota-channel-reached = The target channel has reached its limit of { $max } streams.
+args-usage = Usage: { $usage }
+args-missing-argument = Missing { $name }.
+args-missing-value = The --{ $name } option needs a value.
+args-unknown-option = Unknown option --{ $name }.
+args-too-many = Too many arguments. Put text with spaces in "double quotes".
+args-unclosed-quote = A quote is missing its closing ".
+permission-denied = You need Manage Channel, Manage Server or the stream manager role to use this command.
+value-none = none
+value-any = any
+value-off = off
+value-yes = yes
+value-no = no
+value-server-default = server default
+
+## Notifications
+
+notification-default = { $name } is now live! { $url }
+embed-live = { $name } is live
+embed-playing = Playing **{ $game }**
+embed-viewers = { $count ->
+    [one] { $count } viewer
+   *[other] { $count } viewers
+}
+quiet-summary-title = Went live during quiet hours:
+
+## Templates
+
+template-unknown-placeholder = Unknown placeholder `{ $placeholder }`. Available: { $available }
+template-unclosed-placeholder = A `{"{"}` is never closed. Use `{"{{"}` for a literal brace.
+template-unclosed-section = Section `{ $open }` is missing its `{ $close }`.
+template-unexpected-section-end = `{ $close }` has no matching `{ $open }`.
+template-sample-title = Sample stream title
+
+## Quiet hours
+
+quiet-invalid-start = Invalid start time '{ $value }', expected HH:MM.
+quiet-invalid-end = Invalid end time '{ $value }', expected HH:MM.
+quiet-same-start-end = Start and end time must differ.
+quiet-unknown-timezone = Unknown timezone '{ $value }', e.g. Europe/Berlin.
+quiet-unknown-mode = Unknown mode '{ $value }', use drop, silent or queue.
+
+## !addstream
+
+addstream-added = Added channel: { $channel }
+addstream-added-custom = Added channel: { $channel } (with custom message)
+addstream-added-elsewhere = , notifications go to <#{ $target }>
+addstream-duplicate = This channel is already added in this server.
+addstream-failed = Failed to add channel.
+
+## !liststreams
+
+liststreams-empty = No streams configured for this channel.
+liststreams-empty-server = No streams configured in this server.
+liststreams-title = Streams in this channel ({ $count }):
+liststreams-title-all = Streams in this server ({ $count }):
+liststreams-entry = **{ $channel }**, added by { $user } on { $date }
+liststreams-live = 🔴 live
+liststreams-games = games: { $games }
+liststreams-keywords = keywords: { $keywords }
+liststreams-page = Page { $page } of { $pages }.
+liststreams-next-page = Use { $command } for the next one.
+liststreams-no-page = { $pages ->
+    [one] There is only one page.
+   *[other] There are only { $pages } pages.
+}
+liststreams-custom-message = custom message
+liststreams-invalid-message = ⚠ invalid custom message, the default is posted
+liststreams-disabled = disabled
+liststreams-paused = paused
+
+## !live
+
+live-title = Live now ({ $count }):
+live-details = { $game } · live for { $uptime } · { $viewers }
+live-no-game = No category
+live-more = … and { $count } more
+live-nobody = Nobody is live right now. Check back later!
+live-failed = Couldn't fetch the live streams from Twitch, try again in a moment.
+
+## !editstream
+
+editstream-usage =
+    Usage: !editstream <channel_name> <field> <value>
+    Fields: `message <text|default>`, `games <game, ...|any>`, `keywords <word, ...|any>`, `mention <@role|role name|none|default>`, `mode <text|embed|default>`, `enabled <on|off>`
+editstream-mode-usage = Usage: !editstream <channel_name> mode <text|embed|default>
+editstream-enabled-usage = Usage: !editstream <channel_name> enabled <on|off>
+editstream-not-yours = You can only edit streams you added.
+editstream-updated = **Updated { $channel }:**
+    - Message: { $message }
+    - Games: { $games }
+    - Keywords: { $keywords }
+    - Mention: { $mention }
+    - Mode: { $mode }
+    - Enabled: { $enabled }
+
+## !removestream
+
+removestream-removed = Removed channel: { $channel }
+removestream-not-found = Stream not found.
+removestream-not-yours = You can only remove streams you added.
+removestream-failed = Failed to remove stream.
+
+## !addstreams and !removestreams
+
+bulk-channel = Channel
+bulk-result = Result
+bulk-added = added
+bulk-already-present = already present
+bulk-not-found = not found
+bulk-over-quota = over quota
+bulk-removed = removed
+bulk-not-yours = not yours
+bulk-team = via team { $team }
+bulk-added-elsewhere = Notifications go to <#{ $target }>.
+bulk-more = … and { $count } more
+
+## !addteam and !removeteam
+
+addteam-invalid = '{ $team }' is not a valid Twitch team name.
+addteam-not-found = Twitch team '{ $team }' not found.
+addteam-duplicate = Team **{ $team }** is already followed in this server.
+addteam-added = Following team **{ $team }**, { $count ->
+    [one] added 1 member.
+   *[other] added { $count } members.
+}
+addteam-already-tracked = Already followed on their own: { $members }
+removeteam-not-found = This server doesn't follow a team called '{ $team }'.
+removeteam-not-yours = You can only remove teams you added.
+removeteam-removed = Removed team **{ $team }** and { $count ->
+    [one] 1 stream.
+   *[other] { $count } streams.
+}
+team-sync-title = Team { $team } changed:
+team-sync-joined = ➕ Joined: { $members }
+team-sync-left = ➖ Left: { $members }
+
+## !addcategory and !removecategory
+
+category-entry = { $game } · { $count ->
+    [one] 1+ viewer
+   *[other] { $count }+ viewers
+} · { $language } → <#{ $target }>
+category-any-language = any language
+category-list-title = Followed categories ({ $count }):
+category-list-empty = This server doesn't follow any categories yet.
+category-new-streams = { $count ->
+    [one] New { $game } stream:
+   *[other] { $count } new { $game } streams:
+}
+addcategory-invalid-viewers = '{ $value }' is not a viewer count.
+addcategory-invalid-language = '{ $value }' is not a language code, use two letters like en or de.
+addcategory-limit = This server already follows { $max } categories, the most it can.
+addcategory-not-found = Twitch category '{ $game }' not found. Use its exact name or ID.
+addcategory-added = Now announcing new streams in { $entry }
+addcategory-duplicate = **{ $game }** is already followed in this server.
+removecategory-not-found = This server doesn't follow a category called '{ $game }'.
+removecategory-not-yours = You can only remove categories you added.
+removecategory-removed = Stopped announcing **{ $game }**.
+
+## !movestream
+
+movestream-cannot-send = I can't send messages in <#{ $target }>. Check my permissions there first.
+movestream-not-yours = You can only move streams you added.
+movestream-already-there = { $channel } already notifies <#{ $target }>.
+movestream-duplicate = { $channel } is already set up in <#{ $target }>.
+movestream-moved = { $channel } now notifies <#{ $target }>.
+movestream-failed = Failed to move stream.
+
+## !transferstream
+
+transferstream-user-not-found = { $user } is not a member of this server.
+transferstream-not-yours = You can only transfer streams you added.
+transferstream-already-owner = { $channel } already belongs to <@{ $user }>.
+transferstream-limit-reached = <@{ $user }> has reached the maximum limit of { $max } streams.
+transferstream-transferred = { $channel } now belongs to <@{ $user }>.
+transferstream-failed = Failed to transfer stream.
+
+## !streamconfig
+
+streamconfig-usage = Usage: !streamconfig [cooldown <minutes> | quiet <HH:MM> <HH:MM> <timezone> [drop|silent|queue] | quiet off | template <message|default> | mention <@role|none> | mode <text|embed> | channel <#channel|here|none> | language <code> | prefix <prefix|default> | managerrole <@role|none>]
+streamconfig-summary = **Stream settings for this server:**
+    - Restart cooldown: { $cooldown }
+    - Quiet hours: { $quiet_hours }
+    - Default message: { $message }
+    - Default mention: { $mention }
+    - Message mode: { $mode }
+    - Default channel: { $channel }
+    - Language: { $language }
+    - Command prefix: `{ $prefix }`
+    - Stream manager role: { $manager_role }
+    - { $capacity }
+streamconfig-capacity = Streams left: { $server_left } of { $server_max } in this server, { $channel_left } of { $channel_max } in <#{ $target }>, { $user_left } of { $user_max } for you
+streamconfig-minutes = { $minutes } min
+streamconfig-minutes-default = { $minutes } min (default)
+streamconfig-builtin-message = { $message } (built-in)
+streamconfig-channel-unset = where !addstream is used
+streamconfig-cooldown-usage = Usage: !streamconfig cooldown <minutes> (0-{ $max })
+streamconfig-cooldown-disabled = Restart cooldown disabled.
+streamconfig-cooldown-set = Streams that come back online within { $minutes } min of going offline will not be announced again.
+streamconfig-quiet-usage = Usage: !streamconfig quiet <HH:MM> <HH:MM> <timezone> [drop|silent|queue] or !streamconfig quiet off
+streamconfig-quiet-set = Quiet hours set to { $quiet_hours }.
+streamconfig-quiet-disabled = Quiet hours disabled.
+streamconfig-template-set = Default message set to { $message }.
+streamconfig-template-reset = Default message reset to the built-in one.
+streamconfig-mention-set = Notifications will mention { $role } unless a stream sets its own role.
+streamconfig-mention-reset = Notifications no longer mention a role by default.
+streamconfig-managerrole-set = Members with { $role } can now manage streams.
+streamconfig-managerrole-reset = Stream manager role removed.
+streamconfig-managerrole-admin-only = Only the server owner or members with Manage Server can change the stream manager role.
+streamconfig-prefix-set = Commands now start with `{ $prefix }`. Mentioning me works too.
+streamconfig-prefix-usage = Usage: !streamconfig prefix <prefix|default>, at most { $max } characters without spaces
+streamconfig-mode-set = Notifications are now sent as { $mode } by default.
+streamconfig-mode-usage = Usage: !streamconfig mode <text|embed>
+streamconfig-channel-set = New streams will notify <#{ $channel }> by default.
+streamconfig-channel-reset = New streams will notify the channel !addstream is used in.
+streamconfig-language-set = Bot replies and notifications in this server are now in English.
+streamconfig-language-usage = Unknown language. Available: { $languages }
+
+## !exportstreams and !importstreams
+
+exportstreams-empty = There are no streams in this server to export.
+exportstreams-exported = Exported { $count } streams.
+exportstreams-failed = Failed to upload the export file.
+importstreams-managers-only = Only members with Manage Channel or Manage Server can import streams.
+importstreams-no-file = Attach a JSON or CSV file from !exportstreams to the command.
+importstreams-empty = The file contains no streams.
+importstreams-invalid-file = Couldn't read the file: { $error }
+importstreams-not-text = The file is not UTF-8 text.
+streamfile-invalid-json = Invalid JSON at line { $line }, column { $column }.
+streamfile-unclosed-quote = Line { $line }: a quoted field is never closed.
+streamfile-missing-channel = Line 1: the `channel` column is missing.
+streamfile-invalid-enabled = Line { $line }: '{ $value }' is not a valid enabled value, use true or false.
+attachment-too-large = The file is larger than { $max } KB.
+attachment-status = The file server answered with HTTP { $status }.
+attachment-request-failed = Couldn't reach the file server, try again later.
+importstreams-invalid-mode = Unknown mode '{ $mode }', use text or embed.
+importstreams-duplicate = { $channel } appears more than once for the same target channel.
+importstreams-row-error = Row { $row }: { $error }
+importstreams-invalid-rows = Nothing was imported, { $count } rows have errors:
+importstreams-more = … and { $count } more
+importstreams-no-changes = The file matches the current streams, nothing to import.
+importstreams-summary = **Import preview:** { $added } to add, { $updated } to change, { $removed } to remove, { $unchanged } unchanged
+importstreams-confirm = Reply `yes` within { $seconds } seconds to apply these changes.
+importstreams-timed-out = No answer, the import was cancelled.
+importstreams-cancelled = Import cancelled.
+importstreams-failed = Failed to import streams, nothing was changed.
+importstreams-applied = Import done: { $added } added, { $updated } changed, { $removed } removed.
+
+## !streamquota
+
+streamquota-usage = Usage: !streamquota <server_id|here> [user|server|channel <limit|default>]
+streamquota-operator-only = Only the bot operator can change stream quotas.
+streamquota-unknown-server = I'm not in a server with ID { $server }.
+streamquota-default = { $max } (default)
+streamquota-summary = **Stream quotas for { $server }:**
+    - Per member: { $user }
+    - Per server: { $server_max } ({ $used } used)
+    - Per channel: { $channel }
+
+## !streambotstatus
+
+streambotstatus-title-global = Bot status (all servers)
+streambotstatus-title-server = Bot status for { $server }
+streambotstatus-eventsub = EventSub: { $status }
+streambotstatus-connected = connected, session started { $age } ago
+streambotstatus-disconnected = ⚠️ disconnected, reconnecting
+streambotstatus-keepalive = Last keepalive: { $time }
+streambotstatus-ago = { $age } ago
+streambotstatus-never = never
+streambotstatus-subscriptions = Subscriptions: { $active } active for { $tracked } tracked channels
+streambotstatus-subscriptions-unknown = Subscriptions: unknown (Twitch didn't answer) for { $tracked } tracked channels
+streambotstatus-token-valid = Twitch token: valid, expires in { $expires }
+streambotstatus-token-valid-forever = Twitch token: valid, doesn't expire
+streambotstatus-token-invalid = Twitch token: ⚠️ invalid or expired
+streambotstatus-token-unknown = Twitch token: unknown (Twitch didn't answer)
+streambotstatus-outbox = Outbox: { $pending } pending, { $failed } failed
+streambotstatus-uptime = Uptime: { $uptime } · version { $version }
+streambotstatus-admins-only = Only the server owner and members with Manage Server can see the bot status.
+
+## !teststream
+
+teststream-usage = Usage: !teststream <channel_name> [send]
+teststream-source-live = live data
+teststream-source-sample = sample data, streamer is offline
+teststream-invalid-message = The custom message for this stream is invalid: { $error }
+teststream-preview-title = Preview for { $channel } in <#{ $target }>
+teststream-quiet-active = Quiet hours are active right now ({ $quiet_hours }).
+teststream-send-hint = Use `!teststream { $channel } send` to post it in <#{ $target }>.
+teststream-sent = Test notification sent to <#{ $target }>.
+teststream-sent-reenabled = Test notification sent to <#{ $target }>. Notifications for { $channel } are enabled again.
+teststream-cannot-post = The bot can't post in <#{ $target }>. Check that it can view the channel and send messages.
+teststream-rate-limited = Rate limited by Stoat, try again in a moment.
+teststream-send-failed = Failed to send the test notification.
+
+## !helpstream
+
+help-title = Stream Notification Bot
+help-topics = Help topics:
+help-topic-streams = Add, edit and remove streams
+help-topic-teams = Follow Twitch teams and categories
+help-topic-lists = List, export and import streams
+help-topic-config = Server settings and custom messages
+help-unknown-topic = There is no help topic '{ $topic }'.
+help-addstream = Monitor a Twitch channel
+help-addstream-message = Monitor with custom notification
+help-addstream-options = Set the mention, category filter or embed mode right away
+help-addstreams = Add several channels at once, separated by spaces or commas
+help-editstream-message = Change the notification message
+help-editstream-games = Only notify for these categories
+help-editstream-keywords = Only notify if the title contains one of these
+help-editstream-mention = Mention a role in the notification
+help-editstream-mode = Override the server's message mode
+help-editstream-enabled = Pause or resume notifications
+help-removestream = Stop monitoring a channel
+help-removestreams = Remove several channels at once
+help-addteam = Monitor every member of a Twitch team and follow membership changes
+help-removeteam = Stop monitoring a Twitch team and its members
+help-addcategory = Announce new streams in a category, optionally above a viewer count or in one language
+help-addcategory-list = Show the categories this server follows
+help-removecategory = Stop announcing a category
+help-movestream = Post a stream's notifications in another channel
+help-transferstream = Give a stream to another member
+help-exportstreams = Upload all streams in this server as a JSON or CSV file
+help-importstreams = Import streams from an attached file, with a preview first; --replace also removes streams missing from the file
+help-liststreams = View monitored channels in this channel, a page of them
+help-liststreams-all = View all monitored channels in the server, grouped by target channel
+help-live = Show who is live right now, with title, game, uptime and viewers
+help-streambotstatus = Show the bot's connection, token and queue health (server admins)
+help-teststream = Preview the notification for a channel
+help-teststream-send = Post a test notification to the target channel
+help-streamconfig = Show server stream settings
+help-streamconfig-cooldown = Don't re-announce streams that restart within this time
+help-streamconfig-quiet = Set quiet hours
+help-streamconfig-quiet-off = Disable quiet hours
+help-streamconfig-template = Default message for streams without their own
+help-streamconfig-mention = Role mentioned by default
+help-streamconfig-mode = Post plain text or add a stream details card
+help-streamconfig-channel = Channel new streams notify by default
+help-streamconfig-language = Language for replies and notifications (en, de, pt-BR)
+help-streamconfig-managerrole = Let members with this role manage streams
+help-streamconfig-prefix = Change the command prefix, mention me if you forget it
+help-custom-messages = Custom Messages:
+help-placeholders = Placeholders: { $placeholders }
+help-sections = Wrap text in { $section } to show it only when the field is set. Use { $braces } for literal braces.
+help-example = Example:
+help-example-message = { $name } is live: { $title }
@@ -0,0 +1,365 @@
+# Respostas do bot e notificações, português do Brasil.
+
+## Shared
+
+error-no-user = Não foi possível identificar o usuário.
+error-server-only = Este comando só funciona em canais de texto de servidores.
+invalid-channel-name = Canal inválido. Use um login da Twitch (4-25 letras, números ou sublinhados), um link do twitch.tv ou um ID de broadcaster.
+invalid-custom-message = Mensagem inválida: { $error }
+twitch-channel-not-found = Canal da Twitch '{ $channel }' não encontrado.
+twitch-lookup-failed = Não foi possível validar o canal pela API da Twitch.
+stream-not-in-channel = A stream { $channel } não está configurada neste canal.
+stream-not-in-server = O canal '{ $channel }' não é monitorado neste servidor.
+stream-managed-by-team = O canal '{ $channel }' é anunciado porque este servidor acompanha a equipe **{ $team }**. Use !removeteam { $team } para parar.
+role-not-found = O cargo '{ $role }' não existe neste servidor.
+role-deleted = { $role } (o cargo não existe mais)
+text-channel-not-found = '{ $channel }' não é um canal de texto deste servidor.
+quota-user-reached = Você atingiu o limite de { $max } streams neste servidor.
+quota-server-reached = Este servidor atingiu o limite de { $max } streams.
+quota-channel-reached = O canal de destino atingiu o limite de { $max } streams.
+args-usage = Uso: { $usage }
+args-missing-argument = Falta { $name }.
+args-missing-value = A opção --{ $name } precisa de um valor.
+args-unknown-option = Opção desconhecida --{ $name }.
+args-too-many = Argumentos demais. Coloque textos com espaços entre "aspas duplas".
+args-unclosed-quote = Falta fechar uma aspa ".
+permission-denied = Você precisa de Gerenciar Canal, Gerenciar Servidor ou do cargo de gerente de streams para usar este comando.
+value-none = nenhum
+value-any = qualquer
+value-off = desativado
+value-yes = sim
+value-no = não
+value-server-default = padrão do servidor
+
+## Notifications
+
+notification-default = { $name } está ao vivo! { $url }
+embed-live = { $name } está ao vivo
+embed-playing = Jogando **{ $game }**
+embed-viewers = { $count ->
+    [one] { $count } espectador
+   *[other] { $count } espectadores
+}
+quiet-summary-title = Entraram ao vivo durante o horário silencioso:
+
+## Templates
+
+template-unknown-placeholder = Marcador desconhecido `{ $placeholder }`. Disponíveis: { $available }
+template-unclosed-placeholder = Uma `{"{"}` nunca é fechada. Use `{"{{"}` para uma chave literal.
+template-unclosed-section = Falta o `{ $close }` da seção `{ $open }`.
+template-unexpected-section-end = `{ $close }` não tem um `{ $open }` correspondente.
+template-sample-title = Título de exemplo da stream
+
+## Quiet hours
+
+quiet-invalid-start = Horário de início inválido '{ $value }', use HH:MM.
+quiet-invalid-end = Horário de término inválido '{ $value }', use HH:MM.
+quiet-same-start-end = O início e o término precisam ser diferentes.
+quiet-unknown-timezone = Fuso horário desconhecido '{ $value }', por exemplo America/Sao_Paulo.
+quiet-unknown-mode = Modo desconhecido '{ $value }', use drop, silent ou queue.
+
+## !addstream
+
+addstream-added = Canal adicionado: { $channel }
+addstream-added-custom = Canal adicionado: { $channel } (com mensagem personalizada)
+addstream-added-elsewhere = , as notificações vão para <#{ $target }>
+addstream-duplicate = Este canal já foi adicionado neste servidor.
+addstream-failed = Não foi possível adicionar o canal.
+
+## !liststreams
+
+liststreams-empty = Nenhuma stream configurada para este canal.
+liststreams-empty-server = Nenhuma stream configurada neste servidor.
+liststreams-title = Streams neste canal ({ $count }):
+liststreams-title-all = Streams neste servidor ({ $count }):
+liststreams-entry = **{ $channel }**, adicionada por { $user } em { $date }
+liststreams-live = 🔴 ao vivo
+liststreams-games = jogos: { $games }
+liststreams-keywords = palavras-chave: { $keywords }
+liststreams-page = Página { $page } de { $pages }.
+liststreams-next-page = Use { $command } para a próxima.
+liststreams-no-page = { $pages ->
+    [one] Existe apenas uma página.
+   *[other] Existem apenas { $pages } páginas.
+}
+liststreams-custom-message = mensagem personalizada
+liststreams-invalid-message = ⚠ mensagem personalizada inválida, a padrão é enviada
+liststreams-disabled = desativada
+liststreams-paused = pausada
+
+## !live
+
+live-title = Ao vivo agora ({ $count }):
+live-details = { $game } · ao vivo há { $uptime } · { $viewers }
+live-no-game = Sem categoria
+live-more = … e mais { $count }
+live-nobody = Ninguém está ao vivo agora. Volte mais tarde!
+live-failed = Não foi possível buscar as streams ao vivo na Twitch, tente novamente em instantes.
+
+## !editstream
+
+editstream-usage =
+    Uso: !editstream <nome_do_canal> <campo> <valor>
+    Campos: `message <texto|default>`, `games <jogo, ...|any>`, `keywords <palavra, ...|any>`, `mention <@cargo|nome do cargo|none|default>`, `mode <text|embed|default>`, `enabled <on|off>`
+editstream-mode-usage = Uso: !editstream <nome_do_canal> mode <text|embed|default>
+editstream-enabled-usage = Uso: !editstream <nome_do_canal> enabled <on|off>
+editstream-not-yours = Você só pode editar streams que você adicionou.
+editstream-updated = **{ $channel } atualizado:**
+    - Mensagem: { $message }
+    - Jogos: { $games }
+    - Palavras-chave: { $keywords }
+    - Menção: { $mention }
+    - Modo: { $mode }
+    - Ativo: { $enabled }
+
+## !removestream
+
+removestream-removed = Canal removido: { $channel }
+removestream-not-found = Stream não encontrada.
+removestream-not-yours = Você só pode remover streams que você adicionou.
+removestream-failed = Não foi possível remover a stream.
+
+## !addstreams e !removestreams
+
+bulk-channel = Canal
+bulk-result = Resultado
+bulk-added = adicionado
+bulk-already-present = já existe
+bulk-not-found = não encontrado
+bulk-over-quota = limite atingido
+bulk-removed = removido
+bulk-not-yours = não é seu
+bulk-team = pela equipe { $team }
+bulk-added-elsewhere = As notificações vão para <#{ $target }>.
+bulk-more = … e mais { $count }
+
+## !addteam e !removeteam
+
+addteam-invalid = '{ $team }' não é um nome de equipe da Twitch válido.
+addteam-not-found = Equipe da Twitch '{ $team }' não encontrada.
+addteam-duplicate = A equipe **{ $team }** já é acompanhada neste servidor.
+addteam-added = Acompanhando a equipe **{ $team }**, { $count ->
+    [one] 1 membro adicionado.
+   *[other] { $count } membros adicionados.
+}
+addteam-already-tracked = Já acompanhados individualmente: { $members }
+removeteam-not-found = Este servidor não acompanha nenhuma equipe chamada '{ $team }'.
+removeteam-not-yours = Você só pode remover equipes que você adicionou.
+removeteam-removed = Equipe **{ $team }** e { $count ->
+    [one] 1 stream removidos.
+   *[other] { $count } streams removidos.
+}
+team-sync-title = A equipe { $team } mudou:
+team-sync-joined = ➕ Entraram: { $members }
+team-sync-left = ➖ Saíram: { $members }
+
+## !addcategory e !removecategory
+
+category-entry = { $game } · { $count ->
+    [one] 1+ espectador
+   *[other] { $count }+ espectadores
+} · { $language } → <#{ $target }>
+category-any-language = qualquer idioma
+category-list-title = Categorias acompanhadas ({ $count }):
+category-list-empty = Este servidor ainda não acompanha nenhuma categoria.
+category-new-streams = { $count ->
+    [one] Nova stream de { $game }:
+   *[other] { $count } novas streams de { $game }:
+}
+addcategory-invalid-viewers = '{ $value }' não é um número de espectadores.
+addcategory-invalid-language = '{ $value }' não é um código de idioma, use duas letras como en ou pt.
+addcategory-limit = Este servidor já acompanha { $max } categorias, o máximo permitido.
+addcategory-not-found = Categoria da Twitch '{ $game }' não encontrada. Use o nome exato ou o ID.
+addcategory-added = Agora anunciando novas streams em { $entry }
+addcategory-duplicate = **{ $game }** já é acompanhada neste servidor.
+removecategory-not-found = Este servidor não acompanha nenhuma categoria chamada '{ $game }'.
+removecategory-not-yours = Você só pode remover categorias que você adicionou.
+removecategory-removed = **{ $game }** não será mais anunciada.
+
+## !movestream
+
+movestream-cannot-send = Não consigo enviar mensagens em <#{ $target }>. Verifique minhas permissões lá primeiro.
+movestream-not-yours = Você só pode mover streams que você adicionou.
+movestream-already-there = { $channel } já notifica <#{ $target }>.
+movestream-duplicate = { $channel } já está configurada em <#{ $target }>.
+movestream-moved = { $channel } agora notifica <#{ $target }>.
+movestream-failed = Falha ao mover a stream.
+
+## !transferstream
+
+transferstream-user-not-found = { $user } não é membro deste servidor.
+transferstream-not-yours = Você só pode transferir streams que você adicionou.
+transferstream-already-owner = { $channel } já pertence a <@{ $user }>.
+transferstream-limit-reached = <@{ $user }> atingiu o limite máximo de { $max } streams.
+transferstream-transferred = { $channel } agora pertence a <@{ $user }>.
+transferstream-failed = Falha ao transferir a stream.
+
+## !streamconfig
+
+streamconfig-usage = Uso: !streamconfig [cooldown <minutos> | quiet <HH:MM> <HH:MM> <fuso> [drop|silent|queue] | quiet off | template <mensagem|default> | mention <@cargo|none> | mode <text|embed> | channel <#canal|here|none> | language <código> | prefix <prefixo|default> | managerrole <@cargo|none>]
+streamconfig-summary = **Configurações de streams deste servidor:**
+    - Intervalo após reinício: { $cooldown }
+    - Horário silencioso: { $quiet_hours }
+    - Mensagem padrão: { $message }
+    - Menção padrão: { $mention }
+    - Modo de mensagem: { $mode }
+    - Canal padrão: { $channel }
+    - Idioma: { $language }
+    - Prefixo de comandos: `{ $prefix }`
+    - Cargo de gerente de streams: { $manager_role }
+    - { $capacity }
+streamconfig-capacity = Streams disponíveis: { $server_left } de { $server_max } neste servidor, { $channel_left } de { $channel_max } em <#{ $target }>, { $user_left } de { $user_max } para você
+streamconfig-minutes = { $minutes } min
+streamconfig-minutes-default = { $minutes } min (padrão)
+streamconfig-builtin-message = { $message } (embutida)
+streamconfig-channel-unset = onde o !addstream for usado
+streamconfig-cooldown-usage = Uso: !streamconfig cooldown <minutos> (0-{ $max })
+streamconfig-cooldown-disabled = Intervalo após reinício desativado.
+streamconfig-cooldown-set = Streams que voltarem em até { $minutes } min depois de ficarem offline não serão anunciadas de novo.
+streamconfig-quiet-usage = Uso: !streamconfig quiet <HH:MM> <HH:MM> <fuso> [drop|silent|queue] ou !streamconfig quiet off
+streamconfig-quiet-set = Horário silencioso definido como { $quiet_hours }.
+streamconfig-quiet-disabled = Horário silencioso desativado.
+streamconfig-template-set = Mensagem padrão definida como { $message }.
+streamconfig-template-reset = Mensagem padrão redefinida para a embutida.
+streamconfig-mention-set = As notificações vão mencionar { $role }, a menos que a stream tenha o próprio cargo.
+streamconfig-mention-reset = As notificações não mencionam mais um cargo por padrão.
+streamconfig-managerrole-set = Membros com { $role } agora podem gerenciar streams.
+streamconfig-managerrole-reset = Cargo de gerente de streams removido.
+streamconfig-managerrole-admin-only = Apenas o dono do servidor ou membros com Gerenciar Servidor podem alterar o cargo de gerente de streams.
+streamconfig-prefix-set = Os comandos agora começam com `{ $prefix }`. Mencionar o bot também funciona.
+streamconfig-prefix-usage = Uso: !streamconfig prefix <prefixo|default>, no máximo { $max } caracteres sem espaços
+streamconfig-mode-set = As notificações agora são enviadas como { $mode } por padrão.
+streamconfig-mode-usage = Uso: !streamconfig mode <text|embed>
+streamconfig-channel-set = Novas streams vão notificar <#{ $channel }> por padrão.
+streamconfig-channel-reset = Novas streams vão notificar o canal onde o !addstream for usado.
+streamconfig-language-set = As respostas e notificações do bot neste servidor agora estão em português.
+streamconfig-language-usage = Idioma desconhecido. Disponíveis: { $languages }
+
+## !exportstreams e !importstreams
+
+exportstreams-empty = Não há streams neste servidor para exportar.
+exportstreams-exported = { $count } streams exportadas.
+exportstreams-failed = Falha ao enviar o arquivo de exportação.
+importstreams-managers-only = Apenas membros com Gerenciar Canal ou Gerenciar Servidor podem importar streams.
+importstreams-no-file = Anexe ao comando um arquivo JSON ou CSV gerado pelo !exportstreams.
+importstreams-empty = O arquivo não contém streams.
+importstreams-invalid-file = Não foi possível ler o arquivo: { $error }
+importstreams-not-text = O arquivo não é texto UTF-8.
+streamfile-invalid-json = JSON inválido na linha { $line }, coluna { $column }.
+streamfile-unclosed-quote = Linha { $line }: um campo entre aspas não foi fechado.
+streamfile-missing-channel = Linha 1: falta a coluna `channel`.
+streamfile-invalid-enabled = Linha { $line }: '{ $value }' não é um valor válido para enabled, use true ou false.
+attachment-too-large = O arquivo é maior que { $max } KB.
+attachment-status = O servidor de arquivos respondeu com HTTP { $status }.
+attachment-request-failed = Não foi possível acessar o servidor de arquivos, tente novamente mais tarde.
+importstreams-invalid-mode = Modo '{ $mode }' desconhecido, use text ou embed.
+importstreams-duplicate = { $channel } aparece mais de uma vez para o mesmo canal de destino.
+importstreams-row-error = Linha { $row }: { $error }
+importstreams-invalid-rows = Nada foi importado, { $count } linhas têm erros:
+importstreams-more = … e mais { $count }
+importstreams-no-changes = O arquivo é igual às streams atuais, nada para importar.
+importstreams-summary = **Prévia da importação:** { $added } para adicionar, { $updated } para alterar, { $removed } para remover, { $unchanged } sem alteração
+importstreams-confirm = Responda `sim` em até { $seconds } segundos para aplicar as alterações.
+importstreams-timed-out = Sem resposta, a importação foi cancelada.
+importstreams-cancelled = Importação cancelada.
+importstreams-failed = Falha ao importar as streams, nada foi alterado.
+importstreams-applied = Importação concluída: { $added } adicionadas, { $updated } alteradas, { $removed } removidas.
+
+## !streamquota
+
+streamquota-usage = Uso: !streamquota <id_do_servidor|here> [user|server|channel <limite|default>]
+streamquota-operator-only = Apenas o operador do bot pode alterar as cotas de streams.
+streamquota-unknown-server = Não estou em nenhum servidor com o ID { $server }.
+streamquota-default = { $max } (padrão)
+streamquota-summary = **Cotas de streams de { $server }:**
+    - Por membro: { $user }
+    - Por servidor: { $server_max } ({ $used } em uso)
+    - Por canal: { $channel }
+
+## !streambotstatus
+
+streambotstatus-title-global = Status do bot (todos os servidores)
+streambotstatus-title-server = Status do bot em { $server }
+streambotstatus-eventsub = EventSub: { $status }
+streambotstatus-connected = conectado, sessão iniciada há { $age }
+streambotstatus-disconnected = ⚠️ desconectado, reconectando
+streambotstatus-keepalive = Último keepalive: { $time }
+streambotstatus-ago = há { $age }
+streambotstatus-never = nunca
+streambotstatus-subscriptions = Inscrições: { $active } ativas para { $tracked } canais monitorados
+streambotstatus-subscriptions-unknown = Inscrições: desconhecido (a Twitch não respondeu) para { $tracked } canais monitorados
+streambotstatus-token-valid = Token da Twitch: válido, expira em { $expires }
+streambotstatus-token-valid-forever = Token da Twitch: válido, não expira
+streambotstatus-token-invalid = Token da Twitch: ⚠️ inválido ou expirado
+streambotstatus-token-unknown = Token da Twitch: desconhecido (a Twitch não respondeu)
+streambotstatus-outbox = Fila de envio: { $pending } pendentes, { $failed } com falha
+streambotstatus-uptime = Tempo ativo: { $uptime } · versão { $version }
+streambotstatus-admins-only = Apenas o dono do servidor e membros com Gerenciar Servidor podem ver o status do bot.
+
+## !teststream
+
+teststream-usage = Uso: !teststream <nome_do_canal> [send]
+teststream-source-live = dados ao vivo
+teststream-source-sample = dados de exemplo, o streamer está offline
+teststream-invalid-message = A mensagem desta stream é inválida: { $error }
+teststream-preview-title = Prévia de { $channel } em <#{ $target }>
+teststream-quiet-active = O horário silencioso está ativo agora ({ $quiet_hours }).
+teststream-send-hint = Use `!teststream { $channel } send` para publicá-la em <#{ $target }>.
+teststream-sent = Notificação de teste enviada para <#{ $target }>.
+teststream-sent-reenabled = Notificação de teste enviada para <#{ $target }>. As notificações de { $channel } foram reativadas.
+teststream-cannot-post = O bot não consegue publicar em <#{ $target }>. Verifique se ele pode ver o canal e enviar mensagens.
+teststream-rate-limited = Limite de envio do Stoat atingido, tente de novo em instantes.
+teststream-send-failed = Não foi possível enviar a notificação de teste.
+
+## !helpstream
+
+help-title = Bot de Notificações de Streams
+help-topics = Tópicos de ajuda:
+help-topic-streams = Adicionar, editar e remover streams
+help-topic-teams = Seguir equipes e categorias da Twitch
+help-topic-lists = Listar, exportar e importar streams
+help-topic-config = Configurações do servidor e mensagens personalizadas
+help-unknown-topic = Não existe o tópico de ajuda '{ $topic }'.
+help-addstream = Monitorar um canal da Twitch
+help-addstream-message = Monitorar com notificação personalizada
+help-addstream-options = Define a menção, o filtro de categoria ou o modo embed de uma vez
+help-addstreams = Adiciona vários canais de uma vez, separados por espaços ou vírgulas
+help-editstream-message = Alterar a mensagem de notificação
+help-editstream-games = Notificar só para estas categorias
+help-editstream-keywords = Notificar só se o título tiver uma destas palavras
+help-editstream-mention = Mencionar um cargo na notificação
+help-editstream-mode = Substituir o modo de mensagem do servidor
+help-editstream-enabled = Pausar ou retomar as notificações
+help-removestream = Parar de monitorar um canal
+help-removestreams = Remove vários canais de uma vez
+help-addteam = Acompanha todos os membros de uma equipe da Twitch e suas mudanças
+help-removeteam = Para de acompanhar uma equipe da Twitch e seus membros
+help-addcategory = Anuncia novas streams de uma categoria, opcionalmente acima de um número de espectadores ou em um idioma
+help-addcategory-list = Mostra as categorias acompanhadas neste servidor
+help-removecategory = Para de anunciar uma categoria
+help-movestream = Publica as notificações de uma stream em outro canal
+help-transferstream = Passa uma stream para outro membro
+help-exportstreams = Envia todas as streams deste servidor como arquivo JSON ou CSV
+help-importstreams = Importa streams de um arquivo anexado, com prévia antes; --replace também remove streams que não estão no arquivo
+help-liststreams = Ver os canais monitorados neste canal, por página
+help-liststreams-all = Ver todos os canais monitorados do servidor, agrupados por canal de destino
+help-live = Mostra quem está ao vivo agora, com título, jogo, duração e espectadores
+help-streambotstatus = Mostra a conexão, o token e a fila do bot (administradores do servidor)
+help-teststream = Prévia da notificação de um canal
+help-teststream-send = Publicar uma notificação de teste no canal de destino
+help-streamconfig = Mostrar as configurações de streams do servidor
+help-streamconfig-cooldown = Não anunciar de novo streams que reiniciarem dentro deste tempo
+help-streamconfig-quiet = Definir o horário silencioso
+help-streamconfig-quiet-off = Desativar o horário silencioso
+help-streamconfig-template = Mensagem padrão para streams sem mensagem própria
+help-streamconfig-mention = Cargo mencionado por padrão
+help-streamconfig-mode = Publicar só texto ou incluir um cartão com os detalhes da stream
+help-streamconfig-channel = Canal padrão para novas streams
+help-streamconfig-language = Idioma das respostas e notificações (en, de, pt-BR)
+help-streamconfig-managerrole = Permite que membros com este cargo gerenciem streams
+help-streamconfig-prefix = Altera o prefixo de comandos, mencione o bot se esquecer
+help-custom-messages = Mensagens personalizadas:
+help-placeholders = Marcadores: { $placeholders }
+help-sections = O texto entre { $section } só aparece quando o campo está definido. Use { $braces } para chaves literais.
+help-example = Exemplo:
+help-example-message = { $name } está ao vivo: { $title }
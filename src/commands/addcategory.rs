@@ -0,0 +1,199 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Opt, Spec, parse_args};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::template::escape_markdown;
+use crate::settings::load_server_settings;
+use crate::twitch::categories::{Category, get_game, is_valid_language, load_categories};
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "addcategory",
+    required: &[],
+    optional: &[],
+    rest: Some("category"),
+    rest_required: false,
+    options: &[Opt::value("viewers", "count"), Opt::value("language", "code")],
+};
+
+fn describe_category(category: &Category, locale: Locale) -> String {
+    let language = match &category.language {
+        Some(language) => language.clone(),
+        None => t!(locale, "category-any-language"),
+    };
+    t!(
+        locale,
+        "category-entry",
+        game = escape_markdown(&category.game_name),
+        count = category.min_viewers,
+        language = language,
+        target = category.added_in_channel.as_str()
+    )
+}
+
+pub async fn addcategory(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let (settings, categories) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let settings = load_server_settings(&conn, &server_id_clone)?;
+        let categories = load_categories(&conn, Some(&server_id_clone))?;
+        Ok((settings, categories))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    // Without a category, show the ones the server follows
+    let Some(game_arg) = args.rest() else {
+        let response = if categories.is_empty() {
+            t!(locale, "category-list-empty")
+        } else {
+            let mut response = format!("**{}**", t!(locale, "category-list-title", count = categories.len()));
+            for category in &categories {
+                response.push('\n');
+                response.push_str(&describe_category(category, locale));
+            }
+            response
+        };
+        ctx.message
+            .reply(&ctx, true)
+            .content(response)
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let min_viewers = match args.option("viewers").map(str::parse::<u64>) {
+        None => 0,
+        Some(Ok(count)) => count,
+        Some(Err(_)) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(
+                    locale,
+                    "addcategory-invalid-viewers",
+                    value = args.option("viewers").unwrap_or_default()
+                ))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+    let language = args.option("language").map(str::to_ascii_lowercase);
+    if let Some(language) = language.as_deref().filter(|language| !is_valid_language(language)) {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "addcategory-invalid-language", value = language))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let max = ctx.state.config.max_categories_per_server;
+    if categories.len() as i64 >= max {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "addcategory-limit", max = max))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let game = match get_game(game_arg.trim(), &ctx.state.twitch_token, &ctx.state.client_id).await {
+        Ok(Some(game)) => game,
+        Ok(None) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "addcategory-not-found", game = game_arg))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to look up Twitch category {}: {}", game_arg, e);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "twitch-lookup-failed"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Announcements go to the server's default channel if one is set
+    let category = Category {
+        id: 0,
+        server_id,
+        game_id: game.id,
+        game_name: game.name,
+        added_in_channel: settings
+            .default_channel
+            .unwrap_or_else(|| ctx.message.channel.clone()),
+        user_id: ctx.message.author.clone(),
+        min_viewers,
+        language,
+        primed: false,
+    };
+
+    let db = ctx.state.db.clone();
+    let category_clone = category.clone();
+    let insert_result = tokio::task::spawn_blocking(move || -> Result<usize, Error> {
+        let conn = db.get()?;
+        let category = category_clone;
+        Ok(conn.execute(
+            "INSERT INTO categories (server_id, game_id, game_name, added_in_channel, user_id, min_viewers, language, date)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                category.server_id,
+                category.game_id,
+                category.game_name,
+                category.added_in_channel,
+                category.user_id,
+                category.min_viewers as i64,
+                category.language,
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )?)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+
+    let response = match insert_result {
+        Ok(_) => {
+            println!("✓ Following category {} in server {}", category.game_name, category.server_id);
+            t!(locale, "addcategory-added", entry = describe_category(&category, locale))
+        }
+        Err(e) if e.to_string().contains("UNIQUE constraint failed") => t!(
+            locale,
+            "addcategory-duplicate",
+            game = escape_markdown(&category.game_name)
+        ),
+        Err(e) => return Err(e),
+    };
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
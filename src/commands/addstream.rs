@@ -1,95 +1,132 @@
-use crate::commands::CmdCtx;
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Opt, Spec, parse_args};
+use crate::commands::lookup::{find_role, twitch_login};
 use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::filter::parse_list;
+use crate::notify::template::{MessageMode, Template};
+use crate::quota::{Quotas, Usage};
+use crate::settings::load_server_settings;
 use crate::twitch::{validate_twitch_channel, subscribe_single_channel};
 use rusqlite::params;
 use stoat::MessageExt;
 
+const SPEC: Spec = Spec {
+    name: "addstream",
+    required: &["channel"],
+    optional: &[],
+    rest: Some("message"),
+    rest_required: false,
+    options: &[
+        Opt::value("role", "@role"),
+        Opt::value("game", "games"),
+        Opt::switch("embed"),
+    ],
+};
+
 pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
-    let message_text = ctx.message.content.as_ref().unwrap_or(&String::new()).clone();
-    let parts: Vec<&str> = message_text.split_whitespace().collect();
-    
-    if parts.len() < 2 {
-        ctx.message
-            .reply(&ctx, true)
-            .content("Usage: !addstream <channel_name> [custom_message]".to_string())
-            .build()
-            .await?;
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
         return Ok(());
-    }
-    
-    let channel_name = parts[1].to_lowercase();
+    };
     
-    // Everything after channel name is custom message
-    let custom_message = if parts.len() > 2 {
-        Some(parts[2..].join(" "))
-    } else {
-        None
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
     };
     
-    // Validate channel name
-    if channel_name.is_empty() || channel_name.len() > 25 || !channel_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+    // Everything after the channel name and options is the custom message,
+    // spacing and newlines included
+    let custom_message = args.rest().map(str::to_string);
+    let game_filter = args
+        .option("game")
+        .map(parse_list)
+        .filter(|games| !games.is_empty())
+        .map(|games| games.join(", "));
+    let message_mode = args.flag("embed").then_some(MessageMode::Embed.as_str());
+    
+    if let Some(Err(e)) = custom_message.as_deref().map(Template::parse) {
         ctx.message
             .reply(&ctx, true)
-            .content("Invalid channel name. Must be alphanumeric or underscores, max 25 characters.".to_string())
+            .content(t!(locale, "invalid-custom-message", error = e.message(locale)))
             .build()
             .await?;
         return Ok(());
     }
     
+    
     let user = match ctx.message.user.as_ref() {
         Some(u) => u,
         None => {
             ctx.message
                 .reply(&ctx, true)
-                .content("Unable to identify user.".to_string())
+                .content(t!(locale, "error-no-user"))
                 .build()
                 .await?;
             return Ok(());
         }
     };
     let user_id = user.id.clone();
-    let added_in_channel = ctx.message.channel.clone();
-    
-    // Check if user is server owner (for TextChannel only)
-    let channel = ctx.cache.get_channel(&added_in_channel).unwrap();
-    match channel {
-        stoat::types::Channel::TextChannel { server, .. } => {
-            let server_obj = ctx.cache.get_server(&server).unwrap();
-            if server_obj.owner != user_id {
-                ctx.message
-                    .reply(&ctx, true)
-                    .content("You must be the server owner to add streams here.".to_string())
-                    .build()
-                    .await?;
-                return Ok(());
-            }
-        }
+    
+    // Streams can only be added from server text channels
+    let channel = ctx.cache.get_channel(&ctx.message.channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
         _ => {
             ctx.message
                 .reply(&ctx, true)
-                .content("This command only works in server text channels.".to_string())
+                .content(t!(locale, "error-server-only"))
                 .build()
                 .await?;
             return Ok(());
         }
-    }
+    };
+    
+    let server_obj = ctx.cache.get_server(&server_id).unwrap();
+    let mention_role = match args.option("role") {
+        Some(value) => match find_role(&server_obj, value) {
+            Some(role_id) => Some(role_id),
+            None => {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "role-not-found", role = value))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
     
-    // Check if user already has max streams
+    // Notifications go to the server's default channel if one is set
     let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let settings = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_server_settings(&conn, &server_id_clone)
+    }).await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+    let quotas = Quotas::for_server(&ctx.state.config, &settings);
+    let added_in_channel = settings
+        .default_channel
+        .unwrap_or_else(|| ctx.message.channel.clone());
+    
+    // Check the member's, the server's and the target channel's limits
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
     let user_id_clone = user_id.clone();
-    let max_streams = ctx.state.config.max_streams_per_user;
+    let added_in_channel_clone = added_in_channel.clone();
     
-    let count: i64 = tokio::task::spawn_blocking(move || -> Result<i64, Error> {
+    let usage = tokio::task::spawn_blocking(move || {
         let conn = db.get()?;
-        let mut stmt = conn.prepare("SELECT COUNT(*) FROM streams WHERE user_id = ?1")?;
-        Ok(stmt.query_row(params![user_id_clone], |row| row.get(0))?)
+        Usage::load(&conn, &server_id_clone, &user_id_clone, &added_in_channel_clone)
     }).await
     .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
     
-    if count >= max_streams {
+    if let Err(exceeded) = quotas.check(&usage) {
         ctx.message
             .reply(&ctx, true)
-            .content(format!("You have reached the maximum limit of {} streams.", max_streams))
+            .content(exceeded.message(locale))
             .build()
             .await?;
         return Ok(());
@@ -103,7 +140,7 @@ pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
         Ok(None) => {
             ctx.message
                 .reply(&ctx, true)
-                .content(format!("Twitch channel '{}' not found.", channel_name))
+                .content(t!(locale, "twitch-channel-not-found", channel = channel_name.as_str()))
                 .build()
                 .await?;
             return Ok(());
@@ -112,7 +149,7 @@ pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
             eprintln!("✗ Failed to validate Twitch channel: {}", e);
             ctx.message
                 .reply(&ctx, true)
-                .content("Failed to validate channel with Twitch API.".to_string())
+                .content(t!(locale, "twitch-lookup-failed"))
                 .build()
                 .await?;
             return Ok(());
@@ -124,14 +161,16 @@ pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
     let added_in_channel_clone = added_in_channel.clone();
     let user_id_clone = user_id.clone();
     let custom_message_clone = custom_message.clone();
+    let server_id_clone = server_id.clone();
     
     let insert_result = tokio::task::spawn_blocking(move || -> Result<usize, Error> {
         let conn = db.get()?;
         let date = chrono::Utc::now().to_rfc3339();
         
         Ok(conn.execute(
-            "INSERT INTO streams (user_id, channel_name, added_in_channel, date, custom_message) VALUES (?1, ?2, ?3, ?4, ?5)",
-            params![user_id_clone, channel_name_clone, added_in_channel_clone, date, custom_message_clone],
+            "INSERT INTO streams (user_id, channel_name, added_in_channel, date, custom_message, server_id, mention_role, game_filter, message_mode)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![user_id_clone, channel_name_clone, added_in_channel_clone, date, custom_message_clone, server_id_clone, mention_role, game_filter, message_mode],
         )?)
     }).await
     .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
@@ -154,11 +193,14 @@ pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
                 eprintln!("✗ EventSub session not ready yet");
             }
             
-            let response = if custom_message.is_some() {
-                format!("Added channel: {} (with custom message)", channel_name)
+            let mut response = if custom_message.is_some() {
+                t!(locale, "addstream-added-custom", channel = channel_name.as_str())
             } else {
-                format!("Added channel: {}", channel_name)
+                t!(locale, "addstream-added", channel = channel_name.as_str())
             };
+            if added_in_channel != ctx.message.channel {
+                response.push_str(&t!(locale, "addstream-added-elsewhere", target = added_in_channel.as_str()));
+            }
             
             ctx.message
                 .reply(&ctx, true)
@@ -170,14 +212,14 @@ pub async fn addstream(ctx: CmdCtx) -> Result<(), Error> {
             if e.to_string().contains("UNIQUE constraint failed") {
                 ctx.message
                     .reply(&ctx, true)
-                    .content("This channel is already added in this server.".to_string())
+                    .content(t!(locale, "addstream-duplicate"))
                     .build()
                     .await?;
             } else {
                 eprintln!("✗ Database error adding stream: {}", e);
                 ctx.message
                     .reply(&ctx, true)
-                    .content("Failed to add channel.".to_string())
+                    .content(t!(locale, "addstream-failed"))
                     .build()
                     .await?;
             }
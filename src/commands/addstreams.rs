@@ -0,0 +1,189 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{resolve_channels, split_channels};
+use crate::commands::table::code_table_within;
+use crate::config::{LIST_PAGE_LENGTH, MAX_CONCURRENT_SUBSCRIBES};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::quota::{Quotas, Usage};
+use crate::settings::load_server_settings;
+use crate::twitch::subscribe_broadcaster;
+use crate::twitch::validation::{ChannelInput, parse_channel_input};
+use futures_util::StreamExt;
+use rusqlite::params;
+use std::collections::HashSet;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "addstreams",
+    required: &[],
+    optional: &[],
+    rest: Some("channels"),
+    rest_required: true,
+    options: &[],
+};
+
+enum Outcome {
+    Added,
+    AlreadyPresent,
+    NotFound,
+    OverQuota,
+}
+
+impl Outcome {
+    fn label(&self, locale: Locale) -> String {
+        match self {
+            Outcome::Added => t!(locale, "bulk-added"),
+            Outcome::AlreadyPresent => t!(locale, "bulk-already-present"),
+            Outcome::NotFound => t!(locale, "bulk-not-found"),
+            Outcome::OverQuota => t!(locale, "bulk-over-quota"),
+        }
+    }
+}
+
+pub async fn addstreams(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    let inputs = split_channels(args.rest().unwrap_or_default());
+    let parsed: Vec<Option<ChannelInput>> = inputs.iter().map(|i| parse_channel_input(i)).collect();
+
+    let user_id = ctx.message.author.clone();
+    let channel = ctx.cache.get_channel(&ctx.message.channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let Some(resolved) = resolve_channels(&ctx, &parsed, locale).await? else {
+        return Ok(());
+    };
+
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let user_id_clone = user_id.clone();
+    let current_channel = ctx.message.channel.clone();
+    let (settings, usage, existing) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let settings = load_server_settings(&conn, &server_id_clone)?;
+        let target = settings.default_channel.clone().unwrap_or(current_channel);
+        let usage = Usage::load(&conn, &server_id_clone, &user_id_clone, &target)?;
+        // Like !addstream, a channel is only a duplicate where notifications go
+        let mut stmt =
+            conn.prepare("SELECT channel_name FROM streams WHERE server_id = ?1 AND added_in_channel = ?2")?;
+        let existing: HashSet<String> = stmt
+            .query_map(params![server_id_clone, target], |row| row.get(0))?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok((settings, usage, existing))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let added_in_channel = settings
+        .default_channel
+        .clone()
+        .unwrap_or_else(|| ctx.message.channel.clone());
+
+    // Quotas are checked once for the whole batch
+    let quotas = Quotas::for_server(&ctx.state.config, &settings);
+    let mut remaining = (quotas.per_user - usage.user)
+        .min(quotas.per_server - usage.server)
+        .min(quotas.per_channel - usage.channel)
+        .max(0);
+
+    let mut seen = HashSet::new();
+    let mut outcomes = Vec::new();
+    let mut to_add = Vec::new();
+    for (input, user) in inputs.iter().zip(resolved) {
+        let outcome = match user {
+            None => Outcome::NotFound,
+            Some(user) if existing.contains(&user.login) || !seen.insert(user.login.clone()) => {
+                Outcome::AlreadyPresent
+            }
+            Some(_) if remaining == 0 => Outcome::OverQuota,
+            Some(user) => {
+                remaining -= 1;
+                to_add.push(user);
+                Outcome::Added
+            }
+        };
+        outcomes.push((input.clone(), outcome));
+    }
+
+    if !to_add.is_empty() {
+        let db = ctx.state.db.clone();
+        let logins: Vec<String> = to_add.iter().map(|u| u.login.clone()).collect();
+        let added_in_channel_clone = added_in_channel.clone();
+        tokio::task::spawn_blocking(move || -> Result<(), Error> {
+            let mut conn = db.get()?;
+            let tx = conn.transaction()?;
+            let date = chrono::Utc::now().to_rfc3339();
+            for login in &logins {
+                tx.execute(
+                    "INSERT INTO streams (user_id, channel_name, added_in_channel, date, server_id) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![user_id, login, added_in_channel_clone, date, server_id],
+                )?;
+            }
+            tx.commit()?;
+            Ok(())
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+        println!("✓ Added {} streams in bulk", to_add.len());
+
+        let session_id = ctx.state.session_id.read().clone();
+        match session_id {
+            Some(session_id) => {
+                let state = &ctx.state;
+                futures_util::stream::iter(to_add)
+                    .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |user| {
+                        let session_id = session_id.clone();
+                        async move {
+                            if let Err(e) = subscribe_broadcaster(
+                                &user.login,
+                                &user.id,
+                                &session_id,
+                                &state.twitch_token,
+                                &state.client_id,
+                            )
+                            .await
+                            {
+                                eprintln!("✗ Failed to subscribe to EventSub for {}: {}", user.login, e);
+                            }
+                        }
+                    })
+                    .await;
+            }
+            None => eprintln!("✗ EventSub session not ready yet"),
+        }
+    }
+
+    let header = [t!(locale, "bulk-channel"), t!(locale, "bulk-result")];
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .map(|(input, outcome)| vec![input.clone(), outcome.label(locale)])
+        .collect();
+    let mut response = code_table_within(&header, &rows, LIST_PAGE_LENGTH, locale);
+    if added_in_channel != ctx.message.channel {
+        response.push('\n');
+        response.push_str(&t!(locale, "bulk-added-elsewhere", target = added_in_channel.as_str()));
+    }
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::template::escape_markdown;
+use crate::quota::{Quotas, Usage};
+use crate::settings::load_server_settings;
+use crate::twitch::teams::{Team, get_team, list_logins, parse_team_input, sync_team};
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "addteam",
+    required: &["team"],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn addteam(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let team_arg = args.get("team").unwrap_or_default();
+    let Some(team_name) = parse_team_input(team_arg) else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "addteam-invalid", team = team_arg))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let user_id = ctx.message.author.clone();
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // The whole team counts as one stream of the member who adds it
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let user_id_clone = user_id.clone();
+    let current_channel = ctx.message.channel.clone();
+    let (settings, usage) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let settings = load_server_settings(&conn, &server_id_clone)?;
+        let target = settings.default_channel.clone().unwrap_or(current_channel);
+        let usage = Usage::load(&conn, &server_id_clone, &user_id_clone, &target)?;
+        Ok((settings, usage))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let quotas = Quotas::for_server(&ctx.state.config, &settings);
+    if let Err(exceeded) = quotas.check(&usage) {
+        ctx.message
+            .reply(&ctx, true)
+            .content(exceeded.message(locale))
+            .build()
+            .await?;
+        return Ok(());
+    }
+    let added_in_channel = settings
+        .default_channel
+        .clone()
+        .unwrap_or_else(|| ctx.message.channel.clone());
+
+    let current = match get_team(&team_name, &ctx.state.twitch_token, &ctx.state.client_id).await {
+        Ok(Some(current)) => current,
+        Ok(None) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "addteam-not-found", team = team_name.as_str()))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to fetch Twitch team {}: {}", team_name, e);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "twitch-lookup-failed"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let team = Team {
+        id: 0,
+        server_id,
+        team_name: current.team_name.to_ascii_lowercase(),
+        display_name: current.team_display_name.clone(),
+        added_in_channel: added_in_channel.clone(),
+        user_id,
+        members: Vec::new(),
+    };
+    let team_clone = team.clone();
+    let insert_result = tokio::task::spawn_blocking(move || -> Result<i64, Error> {
+        let conn = db.get()?;
+        let team = team_clone;
+        conn.execute(
+            "INSERT INTO teams (server_id, team_name, display_name, added_in_channel, user_id, date) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                team.server_id,
+                team.team_name,
+                team.display_name,
+                team.added_in_channel,
+                team.user_id,
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+
+    let id = match insert_result {
+        Ok(id) => id,
+        Err(e) if e.to_string().contains("UNIQUE constraint failed") => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "addteam-duplicate", team = escape_markdown(&team.display_name)))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    };
+    let team = Team { id, ..team };
+
+    // The first sync adds every current member
+    let changes = sync_team(&ctx.state, &team, &current).await?;
+    println!("✓ Added team {} with {} members", team.team_name, changes.joined.len());
+
+    let mut response = t!(
+        locale,
+        "addteam-added",
+        team = escape_markdown(&team.display_name),
+        count = changes.joined.len() - changes.already_tracked.len()
+    );
+    if !changes.already_tracked.is_empty() {
+        response.push('\n');
+        response.push_str(&t!(
+            locale,
+            "addteam-already-tracked",
+            members = list_logins(&changes.already_tracked)
+        ));
+    }
+    if added_in_channel != ctx.message.channel {
+        response.push('\n');
+        response.push_str(&t!(locale, "bulk-added-elsewhere", target = added_in_channel.as_str()));
+    }
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,372 @@
+use crate::commands::{CmdCtx, command_text, server_prefix};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use std::collections::HashMap;
+use stoat::MessageExt;
+
+/// A `--name` option a command accepts. Options with a `value` placeholder
+/// take the next argument, the others are on/off switches.
+pub struct Opt {
+    pub name: &'static str,
+    pub value: Option<&'static str>,
+}
+
+impl Opt {
+    pub const fn value(name: &'static str, value: &'static str) -> Self {
+        Self { name, value: Some(value) }
+    }
+
+    pub const fn switch(name: &'static str) -> Self {
+        Self { name, value: None }
+    }
+}
+
+/// What a command takes: required and optional positional arguments, then
+/// optionally free text that keeps its spacing and newlines.
+pub struct Spec {
+    pub name: &'static str,
+    pub required: &'static [&'static str],
+    pub optional: &'static [&'static str],
+    pub rest: Option<&'static str>,
+    pub rest_required: bool,
+    pub options: &'static [Opt],
+}
+
+impl Spec {
+    /// Syntax line generated from the spec, e.g.
+    /// `!addstream <channel> [--role <@role>] [--embed] [message...]`
+    pub fn usage(&self, prefix: &str) -> String {
+        let mut usage = format!("{}{}", prefix, self.name);
+        for name in self.required {
+            usage.push_str(&format!(" <{}>", name));
+        }
+        for name in self.optional {
+            usage.push_str(&format!(" [{}]", name));
+        }
+        for opt in self.options {
+            match opt.value {
+                Some(value) => usage.push_str(&format!(" [--{} <{}>]", opt.name, value)),
+                None => usage.push_str(&format!(" [--{}]", opt.name)),
+            }
+        }
+        match self.rest {
+            Some(name) if self.rest_required => usage.push_str(&format!(" <{}...>", name)),
+            Some(name) => usage.push_str(&format!(" [{}...]", name)),
+            None => {}
+        }
+        usage
+    }
+
+    fn option(&self, name: &str) -> Option<&Opt> {
+        self.options.iter().find(|opt| opt.name == name)
+    }
+
+    /// Parses the text after the command name. Options may appear anywhere
+    /// before the trailing text; once it starts, the remainder is taken as is.
+    pub fn parse(&self, text: &str) -> Result<Args, ArgsError> {
+        let mut args = Args::default();
+        let mut pos = 0;
+        let positional_count = self.required.len() + self.optional.len();
+
+        while let Some((start, end, token)) = next_token(text, pos)? {
+            if let Some(name) = token.text.strip_prefix("--").filter(|_| !token.quoted) {
+                let opt = self
+                    .option(name)
+                    .ok_or_else(|| ArgsError::UnknownOption(name.to_string()))?;
+                pos = end;
+                let value = match opt.value {
+                    Some(_) => {
+                        let (_, value_end, value) = next_token(text, pos)?
+                            .ok_or(ArgsError::MissingValue(opt.name))?;
+                        pos = value_end;
+                        Some(value.text)
+                    }
+                    None => None,
+                };
+                args.options.insert(opt.name, value);
+                continue;
+            }
+
+            if args.positional.len() < positional_count {
+                args.positional.push(token.text);
+                pos = end;
+                continue;
+            }
+
+            if self.rest.is_none() {
+                return Err(ArgsError::TooManyArguments);
+            }
+
+            // A single quoted string is unquoted, anything else is kept verbatim
+            args.rest = if token.quoted && text[end..].trim().is_empty() {
+                Some(token.text)
+            } else {
+                Some(text[start..].trim_end().to_string())
+            };
+            break;
+        }
+
+        if let Some(missing) = self.required.get(args.positional.len()) {
+            return Err(ArgsError::MissingArgument(missing));
+        }
+        if let Some(name) = self.rest.filter(|_| self.rest_required && args.rest.is_none()) {
+            return Err(ArgsError::MissingArgument(name));
+        }
+
+        args.names = self.required.iter().chain(self.optional).copied().collect();
+        Ok(args)
+    }
+}
+
+struct Token {
+    text: String,
+    quoted: bool,
+}
+
+/// Reads the token starting at or after `pos`, returning its byte range.
+/// `"double quotes"` group words, `\"` inside them is a literal quote.
+fn next_token(text: &str, pos: usize) -> Result<Option<(usize, usize, Token)>, ArgsError> {
+    let Some(offset) = text[pos..].find(|c: char| !c.is_whitespace()) else {
+        return Ok(None);
+    };
+    let start = pos + offset;
+
+    if !text[start..].starts_with('"') {
+        let end = text[start..]
+            .find(char::is_whitespace)
+            .map_or(text.len(), |len| start + len);
+        let token = Token { text: text[start..end].to_string(), quoted: false };
+        return Ok(Some((start, end, token)));
+    }
+
+    let mut value = String::new();
+    let mut chars = text[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, '"')) => value.push('"'),
+                Some((_, other)) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+                None => value.push('\\'),
+            },
+            '"' => {
+                let end = start + 1 + i + 1;
+                return Ok(Some((start, end, Token { text: value, quoted: true })));
+            }
+            _ => value.push(c),
+        }
+    }
+
+    Err(ArgsError::UnclosedQuote)
+}
+
+/// Parsed arguments, looked up by the names used in the command's `Spec`.
+#[derive(Debug, Default)]
+pub struct Args {
+    names: Vec<&'static str>,
+    positional: Vec<String>,
+    options: HashMap<&'static str, Option<String>>,
+    rest: Option<String>,
+}
+
+impl Args {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let index = self.names.iter().position(|n| *n == name)?;
+        self.positional.get(index).map(String::as_str)
+    }
+
+    pub fn rest(&self) -> Option<&str> {
+        self.rest.as_deref()
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name)?.as_deref()
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgsError {
+    MissingArgument(&'static str),
+    MissingValue(&'static str),
+    UnknownOption(String),
+    TooManyArguments,
+    UnclosedQuote,
+}
+
+impl ArgsError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            ArgsError::MissingArgument(name) => t!(locale, "args-missing-argument", name = *name),
+            ArgsError::MissingValue(name) => t!(locale, "args-missing-value", name = *name),
+            ArgsError::UnknownOption(name) => t!(locale, "args-unknown-option", name = name.as_str()),
+            ArgsError::TooManyArguments => t!(locale, "args-too-many"),
+            ArgsError::UnclosedQuote => t!(locale, "args-unclosed-quote"),
+        }
+    }
+}
+
+/// Parses a command's arguments, replying with what went wrong and the
+/// command's usage if they don't fit its spec.
+pub async fn parse_args(ctx: &CmdCtx, spec: &Spec, locale: Locale) -> Result<Option<Args>, Error> {
+    let text = command_text(ctx);
+    let text = text.trim_start();
+    // Skip the command name itself
+    let after_name = text.find(char::is_whitespace).map_or("", |i| &text[i..]);
+
+    match spec.parse(after_name) {
+        Ok(args) => Ok(Some(args)),
+        Err(e) => {
+            let prefix = server_prefix(ctx).await?;
+            ctx.message
+                .reply(ctx, true)
+                .content(format!(
+                    "{}\n{}",
+                    e.message(locale),
+                    t!(locale, "args-usage", usage = format!("`{}`", spec.usage(&prefix)))
+                ))
+                .build()
+                .await?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: Spec = Spec {
+        name: "addstream",
+        required: &["channel"],
+        optional: &["target"],
+        rest: Some("message"),
+        rest_required: false,
+        options: &[Opt::value("role", "@role"), Opt::switch("embed")],
+    };
+
+    const NO_REST: Spec = Spec {
+        name: "removeteam",
+        required: &["team"],
+        optional: &[],
+        rest: None,
+        rest_required: false,
+        options: &[],
+    };
+
+    #[test]
+    fn usage_lists_every_argument() {
+        assert_eq!(
+            SPEC.usage("!"),
+            "!addstream <channel> [target] [--role <@role>] [--embed] [message...]"
+        );
+        assert_eq!(NO_REST.usage("?"), "?removeteam <team>");
+    }
+
+    #[test]
+    fn positionals_and_rest() {
+        let args = SPEC.parse(" streamer #live  is   live\n  now ").unwrap();
+        assert_eq!(args.get("channel"), Some("streamer"));
+        assert_eq!(args.get("target"), Some("#live"));
+        assert_eq!(args.rest(), Some("is   live\n  now"));
+        assert_eq!(args.get("message"), None);
+    }
+
+    #[test]
+    fn missing_optional_arguments() {
+        let args = SPEC.parse("streamer").unwrap();
+        assert_eq!(args.get("target"), None);
+        assert_eq!(args.rest(), None);
+        assert!(!args.flag("embed"));
+    }
+
+    #[test]
+    fn options_between_positionals() {
+        let args = SPEC.parse("--embed streamer --role @Live #live hello").unwrap();
+        assert!(args.flag("embed"));
+        assert_eq!(args.option("role"), Some("@Live"));
+        assert_eq!(args.get("channel"), Some("streamer"));
+        assert_eq!(args.get("target"), Some("#live"));
+        assert_eq!(args.rest(), Some("hello"));
+    }
+
+    #[test]
+    fn options_after_the_rest_starts_are_text() {
+        let args = SPEC.parse("streamer #live hello --embed").unwrap();
+        assert!(!args.flag("embed"));
+        assert_eq!(args.rest(), Some("hello --embed"));
+    }
+
+    #[test]
+    fn quoted_arguments() {
+        let args = SPEC.parse(r##"streamer --role "Live pings" "#live chat""##).unwrap();
+        assert_eq!(args.option("role"), Some("Live pings"));
+        assert_eq!(args.get("target"), Some("#live chat"));
+        // A quoted `--` is an argument, not an option
+        let args = SPEC.parse(r#""--embed""#).unwrap();
+        assert_eq!(args.get("channel"), Some("--embed"));
+        assert!(!args.flag("embed"));
+    }
+
+    #[test]
+    fn single_quoted_rest_is_unquoted() {
+        let args = SPEC.parse(r#"streamer #live "  spaced  out  ""#).unwrap();
+        assert_eq!(args.rest(), Some("  spaced  out  "));
+        // Anything after the quotes keeps the whole rest verbatim
+        let args = SPEC.parse(r#"streamer #live "quoted" and more"#).unwrap();
+        assert_eq!(args.rest(), Some(r#""quoted" and more"#));
+    }
+
+    #[test]
+    fn escaped_quotes() {
+        let args = SPEC.parse(r#""say \"hi\"" "back\slash""#).unwrap();
+        assert_eq!(args.get("channel"), Some(r#"say "hi""#));
+        assert_eq!(args.get("target"), Some(r"back\slash"));
+        // Outside quotes a backslash is plain text
+        let args = SPEC.parse(r#"a\"b"#).unwrap();
+        assert_eq!(args.get("channel"), Some(r#"a\"b"#));
+    }
+
+    #[test]
+    fn multibyte_text_and_whitespace() {
+        let args = SPEC.parse("stréamer\u{3000}#läive\u{00a0}🔴 live\u{2003}now").unwrap();
+        assert_eq!(args.get("channel"), Some("stréamer"));
+        assert_eq!(args.get("target"), Some("#läive"));
+        assert_eq!(args.rest(), Some("🔴 live\u{2003}now"));
+        let args = SPEC.parse("\"ü\u{3000}ö\"\u{3000}x").unwrap();
+        assert_eq!(args.get("channel"), Some("ü\u{3000}ö"));
+        assert_eq!(args.get("target"), Some("x"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(SPEC.parse("  ").unwrap_err(), ArgsError::MissingArgument("channel"));
+        assert_eq!(SPEC.parse("streamer --role").unwrap_err(), ArgsError::MissingValue("role"));
+        assert_eq!(
+            SPEC.parse("streamer --nope").unwrap_err(),
+            ArgsError::UnknownOption("nope".to_string())
+        );
+        assert_eq!(SPEC.parse(r#"streamer "unclosed"#).unwrap_err(), ArgsError::UnclosedQuote);
+        assert_eq!(SPEC.parse(r#""ends with \""#).unwrap_err(), ArgsError::UnclosedQuote);
+        assert_eq!(NO_REST.parse("one two").unwrap_err(), ArgsError::TooManyArguments);
+    }
+
+    #[test]
+    fn required_rest() {
+        const SPEC: Spec = Spec {
+            name: "addstreams",
+            required: &[],
+            optional: &[],
+            rest: Some("channels"),
+            rest_required: true,
+            options: &[],
+        };
+        assert_eq!(SPEC.parse("").unwrap_err(), ArgsError::MissingArgument("channels"));
+        assert_eq!(SPEC.parse("a, b c").unwrap().rest(), Some("a, b c"));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::i18n::{Locale, t};
+use std::fmt;
+use stoat::types::File;
+
+/// Why a file couldn't be uploaded to or downloaded from Autumn.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttachmentError {
+    /// Larger than the limit, in bytes
+    TooLarge(usize),
+    /// Autumn answered with an error status, and the response body if any
+    Status(u16, String),
+    /// The request failed or the response wasn't understood
+    Request(String),
+}
+
+impl AttachmentError {
+    /// The error as shown to users in a command reply.
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            AttachmentError::TooLarge(max) => t!(locale, "attachment-too-large", max = max / 1024),
+            AttachmentError::Status(status, _) => t!(locale, "attachment-status", status = *status),
+            AttachmentError::Request(_) => t!(locale, "attachment-request-failed"),
+        }
+    }
+}
+
+impl fmt::Display for AttachmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttachmentError::TooLarge(max) => write!(f, "File is larger than {} bytes", max),
+            AttachmentError::Status(status, body) if body.is_empty() => write!(f, "HTTP {}", status),
+            AttachmentError::Status(status, body) => write!(f, "HTTP {}: {}", status, body),
+            AttachmentError::Request(error) => f.write_str(error),
+        }
+    }
+}
+
+/// Uploads a file to Autumn, Stoat's file server, and returns the ID to
+/// attach it to a message with.
+pub async fn upload_attachment(
+    autumn_url: &str,
+    token: &str,
+    filename: &str,
+    content_type: &str,
+    content: Vec<u8>,
+) -> Result<String, AttachmentError> {
+    let part = reqwest::multipart::Part::bytes(content)
+        .file_name(filename.to_string())
+        .mime_str(content_type)
+        .map_err(|e| AttachmentError::Request(format!("Request error: {}", e)))?;
+    let form = reqwest::multipart::Form::new().part("file", part);
+
+    let resp = reqwest::Client::new()
+        .post(format!("{}/attachments", autumn_url))
+        .header("x-bot-token", token)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| AttachmentError::Request(format!("Request error: {}", e)))?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(AttachmentError::Status(status.as_u16(), body));
+    }
+
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| AttachmentError::Request(format!("Parse error: {}", e)))?;
+    data["id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| AttachmentError::Request("Parse error: no file ID in response".to_string()))
+}
+
+/// Downloads a message attachment, refusing files larger than `max_bytes`.
+pub async fn download_attachment(autumn_url: &str, file: &File, max_bytes: usize) -> Result<Vec<u8>, AttachmentError> {
+    if file.size < 0 || file.size as usize > max_bytes {
+        return Err(AttachmentError::TooLarge(max_bytes));
+    }
+
+    let resp = reqwest::Client::new()
+        .get(format!("{}/{}/{}", autumn_url, file.tag, file.id))
+        .send()
+        .await
+        .map_err(|e| AttachmentError::Request(format!("Request error: {}", e)))?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        return Err(AttachmentError::Status(status.as_u16(), String::new()));
+    }
+
+    let bytes = resp
+        .bytes()
+        .await
+        .map_err(|e| AttachmentError::Request(format!("Request error: {}", e)))?;
+    if bytes.len() > max_bytes {
+        return Err(AttachmentError::TooLarge(max_bytes));
+    }
+    Ok(bytes.to_vec())
+}
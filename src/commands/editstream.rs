@@ -0,0 +1,230 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{describe_role, find_role, find_subscription, reply_not_found, twitch_login};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::filter::parse_list;
+use crate::notify::template::{MessageMode, Template};
+use rusqlite::{OptionalExtension, ToSql, params};
+use stoat::MessageExt;
+
+/// A subscription row as shown after an edit.
+struct StreamConfig {
+    custom_message: Option<String>,
+    game_filter: Option<String>,
+    title_filter: Option<String>,
+    mention_role: Option<String>,
+    message_mode: Option<String>,
+    paused: bool,
+}
+
+const SPEC: Spec = Spec {
+    name: "editstream",
+    required: &["channel", "field"],
+    optional: &[],
+    rest: Some("value"),
+    rest_required: true,
+    options: &[],
+};
+
+pub async fn editstream(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
+    };
+    let field = args.get("field").unwrap_or_default().to_lowercase();
+    let value = args.rest().unwrap_or_default().to_string();
+
+    let current_channel = ctx.message.channel.clone();
+
+    // Streams can only be edited from server text channels
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => ctx.cache.get_server(&server).unwrap(),
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Work out which column to change and its new value
+    let (column, new_value): (&'static str, Box<dyn ToSql + Send>) = match field.as_str() {
+        "message" => {
+            if value.eq_ignore_ascii_case("default") {
+                ("custom_message", Box::new(None::<String>))
+            } else if let Err(e) = Template::parse(&value) {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "invalid-custom-message", error = e.message(locale)))
+                    .build()
+                    .await?;
+                return Ok(());
+            } else {
+                ("custom_message", Box::new(Some(value)))
+            }
+        }
+        "games" | "keywords" => {
+            let column = if field == "games" { "game_filter" } else { "title_filter" };
+            let list = parse_list(&value);
+            if value.eq_ignore_ascii_case("any") || list.is_empty() {
+                (column, Box::new(None::<String>))
+            } else {
+                (column, Box::new(Some(list.join(", "))))
+            }
+        }
+        "mention" => {
+            if value.eq_ignore_ascii_case("default") {
+                ("mention_role", Box::new(None::<String>))
+            } else if value.eq_ignore_ascii_case("none") {
+                // An empty role overrides the server default with no mention
+                ("mention_role", Box::new(Some(String::new())))
+            } else if let Some(role_id) = find_role(&server, &value) {
+                ("mention_role", Box::new(Some(role_id)))
+            } else {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "role-not-found", role = value.as_str()))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        }
+        "mode" => {
+            if value.eq_ignore_ascii_case("default") {
+                ("message_mode", Box::new(None::<String>))
+            } else if let Some(mode) = MessageMode::parse(&value) {
+                ("message_mode", Box::new(Some(mode.as_str())))
+            } else {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "editstream-mode-usage"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        }
+        "enabled" => match value.to_lowercase().as_str() {
+            "on" | "true" | "yes" => ("paused", Box::new(false)),
+            "off" | "false" | "no" => ("paused", Box::new(true)),
+            _ => {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "editstream-enabled-usage"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        },
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "editstream-usage"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.clone();
+    let server_id = server.id.clone();
+    let subscription = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        find_subscription(&conn, &channel_name_clone, &server_id, &current_channel)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    // Anyone can edit their own streams, managers can edit any in the server
+    let stream_id = match subscription {
+        Some((_, owner)) if owner != ctx.message.author && !can_manage_all_streams(&ctx).await => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "editstream-not-yours"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Some((stream_id, _)) => stream_id,
+        None => {
+            let not_found = t!(locale, "stream-not-in-channel", channel = channel_name.as_str());
+            reply_not_found(&ctx, &channel_name, &server.id, not_found, locale).await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let updated = tokio::task::spawn_blocking(move || -> Result<Option<StreamConfig>, Error> {
+        let conn = db.get()?;
+        // `column` is one of the fixed names above, never user input
+        conn.execute(
+            &format!("UPDATE streams SET {} = ?1 WHERE id = ?2", column),
+            params![new_value, stream_id],
+        )?;
+
+        Ok(conn
+            .query_row(
+                "SELECT custom_message, game_filter, title_filter, mention_role, message_mode, paused
+                 FROM streams WHERE id = ?1",
+                params![stream_id],
+                |row| {
+                    Ok(StreamConfig {
+                        custom_message: row.get(0)?,
+                        game_filter: row.get(1)?,
+                        title_filter: row.get(2)?,
+                        mention_role: row.get(3)?,
+                        message_mode: row.get(4)?,
+                        paused: row.get(5)?,
+                    })
+                },
+            )
+            .optional()?)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let response = match updated {
+        Some(config) => {
+            let mention = match config.mention_role.as_deref() {
+                Some("") => t!(locale, "value-none"),
+                Some(id) => describe_role(&server, id, locale),
+                None => t!(locale, "value-server-default"),
+            };
+            t!(
+                locale,
+                "editstream-updated",
+                channel = channel_name.as_str(),
+                message = config
+                    .custom_message
+                    .map(|m| format!("`{}`", m))
+                    .unwrap_or_else(|| t!(locale, "value-server-default")),
+                games = config.game_filter.unwrap_or_else(|| t!(locale, "value-any")),
+                keywords = config.title_filter.unwrap_or_else(|| t!(locale, "value-any")),
+                mention = mention,
+                mode = config
+                    .message_mode
+                    .unwrap_or_else(|| t!(locale, "value-server-default")),
+                enabled = t!(locale, if config.paused { "value-no" } else { "value-yes" }),
+            )
+        }
+        None => t!(locale, "stream-not-in-channel", channel = channel_name.as_str()),
+    };
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use crate::commands::{CmdCtx, server_locale, server_prefix};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::attachments::upload_attachment;
+use crate::commands::streamfile::{self, FileFormat, StoredStream, StreamRow, load_server_streams};
+use crate::error::Error;
+use crate::i18n::t;
+use stoat::MessageExt;
+use stoat::types::{Channel, Server};
+
+const SPEC: Spec = Spec {
+    name: "exportstreams",
+    required: &[],
+    optional: &["json|csv"],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+/// Converts a stored subscription to a file row, adding channel and role names.
+fn to_row(ctx: &CmdCtx, server: &Server, stream: StoredStream) -> StreamRow {
+    let target_channel_name = match ctx.cache.get_channel(&stream.added_in_channel) {
+        Some(Channel::TextChannel { name, .. }) => name,
+        _ => String::new(),
+    };
+    let (mention_role, mention_role_name) = match stream.mention_role {
+        Some(role) if role.is_empty() => (Some("none".to_string()), None),
+        Some(role) => {
+            let name = server.roles.get(&role).map(|r| r.name.clone());
+            (Some(role), name)
+        }
+        None => (None, None),
+    };
+
+    StreamRow {
+        channel: stream.channel_name,
+        target_channel: stream.added_in_channel,
+        target_channel_name,
+        owner: stream.user_id,
+        message: stream.custom_message,
+        games: stream.game_filter,
+        keywords: stream.title_filter,
+        mention_role,
+        mention_role_name,
+        mode: stream.message_mode,
+        enabled: stream.enabled,
+    }
+}
+
+pub async fn exportstreams(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let format = match args.get("json|csv") {
+        None => FileFormat::Json,
+        Some(value) => match FileFormat::parse(value) {
+            Some(format) => format,
+            None => {
+                let prefix = server_prefix(&ctx).await?;
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "args-usage", usage = format!("`{}`", SPEC.usage(&prefix))))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        },
+    };
+
+    let channel = ctx.cache.get_channel(&ctx.message.channel).unwrap();
+    let server = match channel {
+        Channel::TextChannel { server, .. } => ctx.cache.get_server(&server).unwrap(),
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let server_id = server.id.clone();
+    let streams = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_server_streams(&conn, &server_id)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    if streams.is_empty() {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "exportstreams-empty"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let count = streams.len();
+    let rows: Vec<StreamRow> = streams.into_iter().map(|s| to_row(&ctx, &server, s)).collect();
+    let content = streamfile::write(&rows, format);
+    let filename = format!("streams-{}.{}", server.id, format.extension());
+
+    match upload_attachment(
+        ctx.cache.autumn_url(),
+        &ctx.state.stoat_token,
+        &filename,
+        format.content_type(),
+        content.into_bytes(),
+    )
+    .await
+    {
+        Ok(file_id) => {
+            println!("✓ Exported {} streams from server {}", count, server.id);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "exportstreams-exported", count = count))
+                .attachment(file_id)
+                .build()
+                .await?;
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to upload stream export: {}", e);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "exportstreams-failed"))
+                .build()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
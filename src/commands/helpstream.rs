@@ -1,27 +1,149 @@
-use crate::commands::CmdCtx;
+use crate::commands::{CmdCtx, server_locale, server_prefix};
+use crate::commands::args::{Spec, parse_args};
 use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::template::Template;
 use stoat::MessageExt;
 
-pub async fn helpstream(ctx: CmdCtx) -> Result<(), Error> {
-    let help_text = r#"**Stream Notification Bot**
+const SPEC: Spec = Spec {
+    name: "helpstream",
+    required: &[],
+    optional: &["topic"],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+/// Command syntax and the catalog message describing it.
+type Commands = &'static [(&'static str, &'static str)];
+
+const STREAM_COMMANDS: Commands = &[
+    ("!addstream <channel>", "help-addstream"),
+    ("!addstream <channel> <message>", "help-addstream-message"),
+    ("!addstream <channel> --role <@role> --game <games> --embed", "help-addstream-options"),
+    ("!addstreams <channel> <channel> ...", "help-addstreams"),
+    ("!editstream <channel> message <text|default>", "help-editstream-message"),
+    ("!editstream <channel> games <game, ...|any>", "help-editstream-games"),
+    ("!editstream <channel> keywords <word, ...|any>", "help-editstream-keywords"),
+    ("!editstream <channel> mention <@role|none|default>", "help-editstream-mention"),
+    ("!editstream <channel> mode <text|embed|default>", "help-editstream-mode"),
+    ("!editstream <channel> enabled <on|off>", "help-editstream-enabled"),
+    ("!removestream <channel>", "help-removestream"),
+    ("!removestreams <channel> <channel> ...", "help-removestreams"),
+    ("!movestream <channel> <#target>", "help-movestream"),
+    ("!transferstream <channel> <@user>", "help-transferstream"),
+    ("!teststream <channel>", "help-teststream"),
+    ("!teststream <channel> send", "help-teststream-send"),
+];
+
+const TEAM_COMMANDS: Commands = &[
+    ("!addteam <team>", "help-addteam"),
+    ("!removeteam <team>", "help-removeteam"),
+    ("!addcategory [--viewers <count>] [--language <code>] <category>", "help-addcategory"),
+    ("!addcategory", "help-addcategory-list"),
+    ("!removecategory <category>", "help-removecategory"),
+];
+
+const LIST_COMMANDS: Commands = &[
+    ("!liststreams [page]", "help-liststreams"),
+    ("!liststreams all [page]", "help-liststreams-all"),
+    ("!live", "help-live"),
+    ("!exportstreams [json|csv]", "help-exportstreams"),
+    ("!importstreams [--replace]", "help-importstreams"),
+    ("!streambotstatus", "help-streambotstatus"),
+];
 
-**Commands** (Server owner only):
+const CONFIG_COMMANDS: Commands = &[
+    ("!streamconfig", "help-streamconfig"),
+    ("!streamconfig cooldown <minutes>", "help-streamconfig-cooldown"),
+    ("!streamconfig quiet <HH:MM> <HH:MM> <timezone> [drop|silent|queue]", "help-streamconfig-quiet"),
+    ("!streamconfig quiet off", "help-streamconfig-quiet-off"),
+    ("!streamconfig template <message|default>", "help-streamconfig-template"),
+    ("!streamconfig mention <@role|none>", "help-streamconfig-mention"),
+    ("!streamconfig mode <text|embed>", "help-streamconfig-mode"),
+    ("!streamconfig channel <#channel|here|none>", "help-streamconfig-channel"),
+    ("!streamconfig language <code>", "help-streamconfig-language"),
+    ("!streamconfig managerrole <@role|none>", "help-streamconfig-managerrole"),
+    ("!streamconfig prefix <prefix|default>", "help-streamconfig-prefix"),
+];
 
-`!addstream <channel>` - Monitor a Twitch channel
-`!addstream <channel> <message>` - Monitor with custom notification
-`!removestream <channel>` - Stop monitoring a channel
-`!liststreams` - View monitored channels
-`!helpstream` - Show this help message
+/// Help is split into topics so each fits in one message: the topic name,
+/// the catalog message describing it and its commands.
+const TOPICS: &[(&str, &str, Commands)] = &[
+    ("streams", "help-topic-streams", STREAM_COMMANDS),
+    ("teams", "help-topic-teams", TEAM_COMMANDS),
+    ("lists", "help-topic-lists", LIST_COMMANDS),
+    ("config", "help-topic-config", CONFIG_COMMANDS),
+];
 
-**Custom Messages:**
-Use `{channel}` for streamer name and `{url}` for stream link.
+/// The topic overview shown by `!helpstream` without a topic.
+fn overview(locale: Locale, prefix: &str) -> String {
+    let mut text = format!("**{}**\n\n**{}**\n", t!(locale, "help-title"), t!(locale, "help-topics"));
+    for (name, description, _) in TOPICS {
+        text.push_str(&format!("`{}helpstream {}` - {}\n", prefix, name, t!(locale, description)));
+    }
+    text
+}
+
+/// How custom messages work, shown below the config topic.
+fn custom_messages(locale: Locale, prefix: &str) -> String {
+    let example = format!(
+        "{}addstream mychannel 🔴 {}{{?game}} ({{game}}){{/game}} {{url}}",
+        prefix,
+        t!(locale, "help-example-message", name = "{name}", title = "{title}")
+    );
+    format!(
+        "\n**{}**\n{}\n{}\n\n**{}**\n`{}`",
+        t!(locale, "help-custom-messages"),
+        t!(locale, "help-placeholders", placeholders = Template::placeholders()),
+        t!(
+            locale,
+            "help-sections",
+            section = "`{?game}...{/game}`",
+            braces = "`{{` `}}`"
+        ),
+        t!(locale, "help-example"),
+        example
+    )
+}
+
+pub async fn helpstream(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    // Show commands with the prefix this server actually uses
+    let prefix = server_prefix(&ctx).await?;
 
-**Example:**
-`!addstream mychannel 🔴 {channel} is live! {url}`"#;
+    let topic = args
+        .get("topic")
+        .map(|name| TOPICS.iter().find(|(topic, _, _)| topic.eq_ignore_ascii_case(name)));
+    let help_text = match topic {
+        None => overview(locale, &prefix),
+        Some(None) => format!(
+            "{}\n\n{}",
+            t!(locale, "help-unknown-topic", topic = args.get("topic").unwrap_or_default()),
+            overview(locale, &prefix)
+        ),
+        Some(Some((name, description, commands))) => {
+            let mut text = format!("**{}**\n\n", t!(locale, description));
+            for (syntax, description) in commands.iter() {
+                text.push_str(&format!(
+                    "`{}` - {}\n",
+                    syntax.replacen('!', &prefix, 1),
+                    t!(locale, description)
+                ));
+            }
+            if *name == "config" {
+                text.push_str(&custom_messages(locale, &prefix));
+            }
+            text
+        }
+    };
 
     ctx.message
         .reply(&ctx, true)
-        .content(help_text.to_string())
+        .content(help_text)
         .build()
         .await?;
 
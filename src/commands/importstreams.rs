@@ -0,0 +1,736 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Opt, Spec, parse_args};
+use crate::commands::attachments::download_attachment;
+use crate::commands::lookup::{find_role, find_text_channel, resolve_channels, user_id};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::commands::streamfile::{self, FileFormat, StoredStream, StreamRow, load_server_streams};
+use crate::config::{
+    IMPORT_CONFIRM_TIMEOUT_SECS, MAX_CONCURRENT_SUBSCRIBES, MAX_IMPORT_FILE_BYTES, MAX_IMPORT_LINES_SHOWN,
+};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::filter::parse_list;
+use crate::notify::template::{MessageMode, Template};
+use crate::quota::{QuotaExceeded, Quotas};
+use crate::settings::load_server_settings;
+use crate::twitch::teams::{Team, load_teams};
+use crate::twitch::types::HelixUser;
+use crate::twitch::validation::{ChannelInput, parse_channel_input};
+use crate::twitch::{subscribe_broadcaster, unsubscribe_single_channel};
+use futures_util::StreamExt;
+use rusqlite::params;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use stoat::MessageExt;
+use stoat::types::{Channel, Server};
+
+const SPEC: Spec = Spec {
+    name: "importstreams",
+    required: &[],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[Opt::switch("replace")],
+};
+
+enum Change {
+    Add(StoredStream),
+    Update(StoredStream, Vec<&'static str>),
+    Remove(StoredStream),
+}
+
+/// Finds a row's target channel by ID or mention, then by name.
+fn find_target(ctx: &CmdCtx, server: &Server, row: &StreamRow) -> Option<String> {
+    if let Some(id) = find_text_channel(&ctx.cache, &server.id, &row.target_channel) {
+        return Some(id);
+    }
+    let name = row.target_channel_name.trim_start_matches('#');
+    if name.is_empty() {
+        return None;
+    }
+    server.channels.iter().find_map(|id| match ctx.cache.get_channel(id) {
+        Some(Channel::TextChannel { id, name: channel_name, .. }) if channel_name.eq_ignore_ascii_case(name) => {
+            Some(id)
+        }
+        _ => None,
+    })
+}
+
+/// Checks one row and turns it into the subscription it describes.
+fn validate_row(
+    ctx: &CmdCtx,
+    server: &Server,
+    row: &StreamRow,
+    user: Option<&HelixUser>,
+    owner: &str,
+    default_target: &str,
+    locale: Locale,
+) -> Result<StoredStream, String> {
+    let Some(user) = user else {
+        return Err(match parse_channel_input(&row.channel) {
+            Some(_) => t!(locale, "twitch-channel-not-found", channel = row.channel.as_str()),
+            None => t!(locale, "invalid-channel-name"),
+        });
+    };
+
+    let added_in_channel = if row.target_channel.is_empty() && row.target_channel_name.is_empty() {
+        default_target.to_string()
+    } else {
+        find_target(ctx, server, row).ok_or_else(|| {
+            let channel = if row.target_channel.is_empty() {
+                &row.target_channel_name
+            } else {
+                &row.target_channel
+            };
+            t!(locale, "text-channel-not-found", channel = channel.as_str())
+        })?
+    };
+
+    if let Some(message) = &row.message
+        && let Err(e) = Template::parse(message)
+    {
+        return Err(t!(locale, "invalid-custom-message", error = e.message(locale)));
+    }
+
+    let filter = |value: &Option<String>| {
+        let list = parse_list(value.as_deref().unwrap_or_default());
+        Some(list.join(", ")).filter(|joined| !joined.is_empty() && !joined.eq_ignore_ascii_case("any"))
+    };
+
+    let mention_role = match &row.mention_role {
+        None => None,
+        Some(role) if role.eq_ignore_ascii_case("none") => Some(String::new()),
+        Some(role) => {
+            let by_name = row.mention_role_name.as_deref().and_then(|name| find_role(server, name));
+            Some(
+                find_role(server, role)
+                    .or(by_name)
+                    .ok_or_else(|| t!(locale, "role-not-found", role = role.as_str()))?,
+            )
+        }
+    };
+
+    let message_mode = match &row.mode {
+        None => None,
+        Some(mode) => Some(
+            MessageMode::parse(mode)
+                .ok_or_else(|| t!(locale, "importstreams-invalid-mode", mode = mode.as_str()))?
+                .as_str()
+                .to_string(),
+        ),
+    };
+
+    Ok(StoredStream {
+        id: None,
+        channel_name: user.login.clone(),
+        added_in_channel,
+        user_id: owner.to_string(),
+        custom_message: row.message.clone(),
+        game_filter: filter(&row.games),
+        title_filter: filter(&row.keywords),
+        mention_role,
+        message_mode,
+        enabled: row.enabled,
+    })
+}
+
+/// Names of the settings that differ between two versions of a subscription.
+fn changed_fields(old: &StoredStream, new: &StoredStream) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if old.user_id != new.user_id {
+        fields.push("owner");
+    }
+    if old.custom_message != new.custom_message {
+        fields.push("message");
+    }
+    if old.game_filter != new.game_filter {
+        fields.push("games");
+    }
+    if old.title_filter != new.title_filter {
+        fields.push("keywords");
+    }
+    if old.mention_role != new.mention_role {
+        fields.push("mention");
+    }
+    if old.message_mode != new.message_mode {
+        fields.push("mode");
+    }
+    if old.enabled != new.enabled {
+        fields.push("enabled");
+    }
+    fields
+}
+
+/// Entries per key, e.g. per owner or per target channel.
+fn tally<'a>(keys: impl Iterator<Item = &'a str>) -> HashMap<&'a str, usize> {
+    let mut counts = HashMap::new();
+    for key in keys {
+        *counts.entry(key).or_default() += 1;
+    }
+    counts
+}
+
+// Whether some key ends up over `max` with more entries than it had before
+fn made_worse(before: &HashMap<&str, usize>, after: &HashMap<&str, usize>, max: i64) -> bool {
+    after
+        .iter()
+        .any(|(key, count)| *count as i64 > max && *count > before.get(key).copied().unwrap_or(0))
+}
+
+/// Checks the member, server and channel totals the import would leave
+/// behind, the same limits `!addstream` enforces. Teams count as one entry
+/// each and are left as they are. Limits that are already exceeded only fail
+/// if the import makes it worse.
+fn check_quotas(
+    quotas: &Quotas,
+    existing: &[StoredStream],
+    teams: &[Team],
+    changes: &[Change],
+) -> Result<(), QuotaExceeded> {
+    // Owner of each stream after the import, keyed by login and target channel
+    let mut streams: HashMap<(&str, &str), &str> = existing
+        .iter()
+        .map(|s| ((s.channel_name.as_str(), s.added_in_channel.as_str()), s.user_id.as_str()))
+        .collect();
+    for change in changes {
+        match change {
+            Change::Add(stream) | Change::Update(stream, _) => {
+                streams.insert((&stream.channel_name, &stream.added_in_channel), &stream.user_id);
+            }
+            Change::Remove(stream) => {
+                streams.remove(&(stream.channel_name.as_str(), stream.added_in_channel.as_str()));
+            }
+        }
+    }
+
+    let team_owners = teams.iter().map(|team| team.user_id.as_str());
+    let owners_before = tally(existing.iter().map(|s| s.user_id.as_str()).chain(team_owners.clone()));
+    let owners_after = tally(streams.values().copied().chain(team_owners));
+    if made_worse(&owners_before, &owners_after, quotas.per_user) {
+        return Err(QuotaExceeded::User(quotas.per_user));
+    }
+
+    let total = streams.len() + teams.len();
+    if total as i64 > quotas.per_server && streams.len() > existing.len() {
+        return Err(QuotaExceeded::Server(quotas.per_server));
+    }
+
+    let team_targets = teams.iter().map(|team| team.added_in_channel.as_str());
+    let targets_before = tally(
+        existing
+            .iter()
+            .map(|s| s.added_in_channel.as_str())
+            .chain(team_targets.clone()),
+    );
+    let targets_after = tally(streams.keys().map(|(_, target)| *target).chain(team_targets));
+    if made_worse(&targets_before, &targets_after, quotas.per_channel) {
+        return Err(QuotaExceeded::Channel(quotas.per_channel));
+    }
+    Ok(())
+}
+
+/// Shows the first few entries of a list, then how many were left out.
+fn truncated(lines: Vec<String>, locale: Locale) -> String {
+    let hidden = lines.len().saturating_sub(MAX_IMPORT_LINES_SHOWN);
+    let mut text = lines.into_iter().take(MAX_IMPORT_LINES_SHOWN).collect::<Vec<_>>().join("\n");
+    if hidden > 0 {
+        text.push('\n');
+        text.push_str(&t!(locale, "importstreams-more", count = hidden));
+    }
+    text
+}
+
+pub async fn importstreams(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    let replace = args.flag("replace");
+
+    let current_channel = ctx.message.channel.clone();
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server = match channel {
+        Channel::TextChannel { server, .. } => ctx.cache.get_server(&server).unwrap(),
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // An import can change anyone's streams
+    if !can_manage_all_streams(&ctx).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "importstreams-managers-only"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let Some(file) = ctx.message.attachments.as_ref().and_then(|files| files.first()) else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "importstreams-no-file"))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let content = match download_attachment(ctx.cache.autumn_url(), file, MAX_IMPORT_FILE_BYTES).await {
+        Ok(bytes) => String::from_utf8(bytes).map_err(|_| t!(locale, "importstreams-not-text")),
+        Err(e) => {
+            eprintln!("✗ Failed to download import file {}: {}", file.filename, e);
+            Err(e.message(locale))
+        }
+    };
+    let rows = content.and_then(|content| {
+        streamfile::read(&content, FileFormat::detect(&file.filename, &content)).map_err(|e| e.message(locale))
+    });
+    let rows = match rows {
+        Ok(rows) if !rows.is_empty() => rows,
+        Ok(_) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "importstreams-empty"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "importstreams-invalid-file", error = e))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Look up every channel on Twitch in one go
+    let parsed: Vec<Option<ChannelInput>> = rows.iter().map(|row| parse_channel_input(&row.channel)).collect();
+    let Some(users) = resolve_channels(&ctx, &parsed, locale).await? else {
+        return Ok(());
+    };
+
+    // Owners who aren't in this server, e.g. in a file from another one, hand
+    // their streams to whoever runs the import
+    let importer = ctx.message.author.clone();
+    let mut members = HashSet::new();
+    for owner in rows.iter().map(|row| user_id(&row.owner)).collect::<HashSet<_>>() {
+        if !owner.is_empty() && ctx.http.fetch_member(&server.id, owner).await.is_ok() {
+            members.insert(owner.to_string());
+        }
+    }
+
+    let db = ctx.state.db.clone();
+    let server_id = server.id.clone();
+    let (settings, existing, teams) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        Ok((
+            load_server_settings(&conn, &server_id)?,
+            load_server_streams(&conn, &server_id)?,
+            load_teams(&conn, Some(&server_id))?,
+        ))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+    let default_target = settings.default_channel.clone().unwrap_or(current_channel);
+
+    let mut errors = Vec::new();
+    let mut imported: Vec<StoredStream> = Vec::new();
+    let mut broadcaster_ids = HashMap::new();
+    for (index, (row, user)) in rows.iter().zip(&users).enumerate() {
+        let owner = Some(user_id(&row.owner))
+            .filter(|owner| members.contains(*owner))
+            .unwrap_or(&importer);
+        match validate_row(&ctx, &server, row, user.as_ref(), owner, &default_target, locale) {
+            Ok(stream)
+                if imported
+                    .iter()
+                    .any(|s| s.channel_name == stream.channel_name && s.added_in_channel == stream.added_in_channel) =>
+            {
+                let error = t!(locale, "importstreams-duplicate", channel = stream.channel_name.as_str());
+                errors.push(t!(locale, "importstreams-row-error", row = index + 1, error = error));
+            }
+            Ok(stream) => {
+                if let Some(user) = user {
+                    broadcaster_ids.insert(user.login.clone(), user.id.clone());
+                }
+                imported.push(stream);
+            }
+            Err(error) => errors.push(t!(locale, "importstreams-row-error", row = index + 1, error = error)),
+        }
+    }
+
+    if !errors.is_empty() {
+        ctx.message
+            .reply(&ctx, true)
+            .content(format!(
+                "{}\n{}",
+                t!(locale, "importstreams-invalid-rows", count = errors.len()),
+                truncated(errors, locale)
+            ))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    // Work out what would change, keyed by Twitch login and target channel
+    let current: HashMap<(&str, &str), &StoredStream> = existing
+        .iter()
+        .map(|s| ((s.channel_name.as_str(), s.added_in_channel.as_str()), s))
+        .collect();
+    let mut changes = Vec::new();
+    for stream in &imported {
+        match current.get(&(stream.channel_name.as_str(), stream.added_in_channel.as_str())) {
+            None => changes.push(Change::Add(stream.clone())),
+            Some(old) => {
+                let fields = changed_fields(old, stream);
+                if !fields.is_empty() {
+                    let stream = StoredStream { id: old.id, ..stream.clone() };
+                    changes.push(Change::Update(stream, fields));
+                }
+            }
+        }
+    }
+    if replace {
+        for stream in &existing {
+            if !imported
+                .iter()
+                .any(|s| s.channel_name == stream.channel_name && s.added_in_channel == stream.added_in_channel)
+            {
+                changes.push(Change::Remove(stream.clone()));
+            }
+        }
+    }
+
+    if changes.is_empty() {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "importstreams-no-changes"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let quotas = Quotas::for_server(&ctx.state.config, &settings);
+    if let Err(e) = check_quotas(&quotas, &existing, &teams, &changes) {
+        ctx.message
+            .reply(&ctx, true)
+            .content(e.message(locale))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let count = |f: fn(&Change) -> bool| changes.iter().filter(|c| f(c)).count();
+    let added = count(|c| matches!(c, Change::Add(_)));
+    let updated = count(|c| matches!(c, Change::Update(..)));
+    let removed = count(|c| matches!(c, Change::Remove(_)));
+    let unchanged = imported.len() - added - updated;
+
+    let diff: Vec<String> = changes
+        .iter()
+        .map(|change| match change {
+            Change::Add(s) => format!("+ **{}** → <#{}>", s.channel_name, s.added_in_channel),
+            Change::Update(s, fields) => {
+                format!("~ **{}** → <#{}>: {}", s.channel_name, s.added_in_channel, fields.join(", "))
+            }
+            Change::Remove(s) => format!("- **{}** → <#{}>", s.channel_name, s.added_in_channel),
+        })
+        .collect();
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(format!(
+            "{}\n{}\n\n{}",
+            t!(
+                locale,
+                "importstreams-summary",
+                added = added,
+                updated = updated,
+                removed = removed,
+                unchanged = unchanged
+            ),
+            truncated(diff, locale),
+            t!(locale, "importstreams-confirm", seconds = IMPORT_CONFIRM_TIMEOUT_SECS)
+        ))
+        .build()
+        .await?;
+
+    let author = ctx.message.author.clone();
+    let channel_id = ctx.message.channel.clone();
+    let answer = ctx
+        .notifiers
+        .wait_for_message(
+            move |message| message.author == author && message.channel == channel_id,
+            Some(Duration::from_secs(IMPORT_CONFIRM_TIMEOUT_SECS)),
+        )
+        .await;
+    let confirmed = match answer {
+        Ok(message) => {
+            let answer = message.content.unwrap_or_default().trim().to_lowercase();
+            answer == "yes" || answer == "y" || answer == t!(locale, "value-yes").to_lowercase()
+        }
+        Err(stoat::Error::Timeout) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "importstreams-timed-out"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if !confirmed {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "importstreams-cancelled"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let server_id = server.id.clone();
+    let new_logins: Vec<String> = changes
+        .iter()
+        .filter_map(|c| match c {
+            Change::Add(s) => Some(s.channel_name.clone()),
+            _ => None,
+        })
+        .collect();
+    let result = tokio::task::spawn_blocking(move || -> Result<Vec<String>, Error> {
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+        let date = chrono::Utc::now().to_rfc3339();
+        let mut removed_logins = Vec::new();
+        for change in &changes {
+            match change {
+                Change::Add(s) => {
+                    tx.execute(
+                        "INSERT INTO streams (user_id, channel_name, added_in_channel, date, custom_message, server_id, mention_role, game_filter, title_filter, message_mode, paused)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                        params![
+                            s.user_id,
+                            s.channel_name,
+                            s.added_in_channel,
+                            date,
+                            s.custom_message,
+                            server_id,
+                            s.mention_role,
+                            s.game_filter,
+                            s.title_filter,
+                            s.message_mode,
+                            !s.enabled
+                        ],
+                    )?;
+                }
+                Change::Update(s, _) => {
+                    // Duplicates from older data go first, so a new owner can't
+                    // collide with one of them
+                    tx.execute(
+                        "DELETE FROM streams
+                         WHERE server_id = ?1 AND channel_name = ?2 AND added_in_channel = ?3 AND team_id IS NULL AND id != ?4",
+                        params![server_id, s.channel_name, s.added_in_channel, s.id],
+                    )?;
+                    tx.execute(
+                        "UPDATE streams SET user_id = ?1, custom_message = ?2, mention_role = ?3,
+                         game_filter = ?4, title_filter = ?5, message_mode = ?6, paused = ?7
+                         WHERE id = ?8",
+                        params![
+                            s.user_id,
+                            s.custom_message,
+                            s.mention_role,
+                            s.game_filter,
+                            s.title_filter,
+                            s.message_mode,
+                            !s.enabled,
+                            s.id
+                        ],
+                    )?;
+                }
+                Change::Remove(s) => {
+                    tx.execute(
+                        "DELETE FROM streams
+                         WHERE server_id = ?1 AND channel_name = ?2 AND added_in_channel = ?3 AND team_id IS NULL",
+                        params![server_id, s.channel_name, s.added_in_channel],
+                    )?;
+                    removed_logins.push(s.channel_name.clone());
+                }
+            }
+        }
+
+        // Channels no other server follows any more
+        let mut unused = Vec::new();
+        for login in removed_logins {
+            let count: i64 = tx.query_row(
+                "SELECT COUNT(*) FROM streams WHERE channel_name = ?1",
+                params![login],
+                |row| row.get(0),
+            )?;
+            if count == 0 {
+                unused.push(login);
+            }
+        }
+        tx.commit()?;
+        Ok(unused)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+
+    let unused = match result {
+        Ok(unused) => unused,
+        Err(e) => {
+            eprintln!("✗ Error importing streams: {}", e);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "importstreams-failed"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    println!(
+        "✓ Imported streams into server {}: {} added, {} updated, {} removed",
+        server.id, added, updated, removed
+    );
+
+    let state = &ctx.state;
+    let session_id = state.session_id.read().clone();
+    match session_id {
+        Some(session_id) => {
+            futures_util::stream::iter(new_logins)
+                .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |login| {
+                    let session_id = session_id.clone();
+                    let broadcaster_id = broadcaster_ids.get(&login).cloned().unwrap_or_default();
+                    async move {
+                        if let Err(e) = subscribe_broadcaster(
+                            &login,
+                            &broadcaster_id,
+                            &session_id,
+                            &state.twitch_token,
+                            &state.client_id,
+                        )
+                        .await
+                        {
+                            eprintln!("✗ Failed to subscribe to EventSub for {}: {}", login, e);
+                        }
+                    }
+                })
+                .await;
+        }
+        None if added > 0 => eprintln!("✗ EventSub session not ready yet"),
+        None => {}
+    }
+    futures_util::stream::iter(unused)
+        .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |login| async move {
+            unsubscribe_single_channel(&login, &state.twitch_token, &state.client_id).await;
+        })
+        .await;
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(t!(
+            locale,
+            "importstreams-applied",
+            added = added,
+            updated = updated,
+            removed = removed
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUOTAS: Quotas = Quotas { per_user: 2, per_server: 10, per_channel: 5 };
+
+    fn stream(channel: &str, target: &str, owner: &str) -> StoredStream {
+        StoredStream {
+            id: None,
+            channel_name: channel.to_string(),
+            added_in_channel: target.to_string(),
+            user_id: owner.to_string(),
+            custom_message: None,
+            game_filter: None,
+            title_filter: None,
+            mention_role: None,
+            message_mode: None,
+            enabled: true,
+        }
+    }
+
+    fn team(target: &str, owner: &str) -> Team {
+        Team {
+            id: 1,
+            server_id: "server".to_string(),
+            team_name: "team".to_string(),
+            display_name: "Team".to_string(),
+            added_in_channel: target.to_string(),
+            user_id: owner.to_string(),
+            members: Vec::new(),
+        }
+    }
+
+    fn is_user_limit(result: Result<(), QuotaExceeded>) -> bool {
+        matches!(result, Err(QuotaExceeded::User(2)))
+    }
+
+    #[test]
+    fn rows_handed_to_one_member_count_against_their_limit() {
+        let changes: Vec<Change> = ["a", "b", "c"]
+            .iter()
+            .map(|channel| Change::Add(stream(channel, "live", "importer")))
+            .collect();
+        assert!(is_user_limit(check_quotas(&QUOTAS, &[], &[], &changes)));
+        assert!(check_quotas(&QUOTAS, &[], &[], &changes[..2]).is_ok());
+    }
+
+    #[test]
+    fn teams_and_transfers_count_for_their_owner() {
+        let existing = [stream("a", "live", "alice"), stream("b", "live", "bob")];
+        let changes = [Change::Update(stream("b", "live", "alice"), vec!["owner"])];
+        assert!(check_quotas(&QUOTAS, &existing, &[], &changes).is_ok());
+        assert!(is_user_limit(check_quotas(&QUOTAS, &existing, &[team("live", "alice")], &changes)));
+    }
+
+    #[test]
+    fn members_already_over_the_limit_may_shrink() {
+        let existing = [
+            stream("a", "live", "alice"),
+            stream("b", "live", "alice"),
+            stream("c", "live", "alice"),
+        ];
+        let removed = [Change::Remove(stream("c", "live", "alice"))];
+        assert!(check_quotas(&QUOTAS, &existing, &[], &removed).is_ok());
+        let added = [Change::Add(stream("d", "live", "alice"))];
+        assert!(is_user_limit(check_quotas(&QUOTAS, &existing, &[], &added)));
+    }
+
+    #[test]
+    fn server_and_channel_limits() {
+        let changes: Vec<Change> = (0..6)
+            .map(|i| Change::Add(stream(&format!("s{}", i), "live", &format!("u{}", i))))
+            .collect();
+        assert!(matches!(
+            check_quotas(&QUOTAS, &[], &[], &changes),
+            Err(QuotaExceeded::Channel(5))
+        ));
+        let teams: Vec<Team> = (0..5).map(|i| team(&format!("t{}", i), "x")).collect();
+        assert!(matches!(
+            check_quotas(&QUOTAS, &[], &teams, &changes),
+            Err(QuotaExceeded::Server(10))
+        ));
+    }
+}
@@ -1,63 +1,317 @@
-use crate::commands::CmdCtx;
+use crate::commands::{CmdCtx, server_locale, server_prefix};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::describe_user;
+use crate::config::LIST_PAGE_LENGTH;
 use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::template::Template;
+use crate::twitch::streams::get_streams;
+use rusqlite::params;
+use std::collections::HashSet;
 use stoat::MessageExt;
 
-pub async fn liststreams(ctx: CmdCtx) -> Result<(), Error> {
-    let added_in_channel = ctx.message.channel.clone();
+const SPEC: Spec = Spec {
+    name: "liststreams",
+    required: &[],
+    optional: &["all", "page"],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+/// A subscription as shown in the listing.
+struct Entry {
+    channel_name: String,
+    added_in_channel: String,
+    user_id: String,
+    date: String,
+    custom_message: Option<String>,
+    game_filter: Option<String>,
+    title_filter: Option<String>,
+    enabled: bool,
+    paused: bool,
+}
+
+/// Splits grouped lines into pages that each fit in one message. A group's
+/// header is repeated when the group continues on the next page.
+fn paginate(groups: Vec<(Option<String>, Vec<String>)>) -> Vec<String> {
+    let mut pages = Vec::new();
+    let mut page = String::new();
+    for (header, lines) in groups {
+        let mut pending_header = header.clone();
+        for line in lines {
+            let needed = line.len() + 1 + pending_header.as_ref().map_or(0, |h| h.len() + 1);
+            if !page.is_empty() && page.len() + needed > LIST_PAGE_LENGTH {
+                pages.push(std::mem::take(&mut page));
+                pending_header = header.clone();
+            }
+            if let Some(header) = pending_header.take() {
+                page.push_str(&header);
+                page.push('\n');
+            }
+            page.push_str(&line);
+            page.push('\n');
+        }
+    }
+    if !page.is_empty() {
+        pages.push(page);
+    }
+    pages
+}
 
-    // Check if this is a server text channel
-    let channel = ctx.cache.get_channel(&added_in_channel).unwrap();
-    match channel {
-        stoat::types::Channel::TextChannel { .. } => {
-            // Valid channel type
+fn describe_entry(ctx: &CmdCtx, server_id: &str, entry: &Entry, live: &HashSet<String>, locale: Locale) -> String {
+    // Dates are stored as RFC 3339, only the day is shown
+    let date = entry.date.get(..10).unwrap_or(&entry.date);
+    let mut parts = vec![t!(
+        locale,
+        "liststreams-entry",
+        channel = entry.channel_name.as_str(),
+        user = describe_user(&ctx.cache, server_id, &entry.user_id),
+        date = date
+    )];
+    if live.contains(&entry.channel_name) {
+        parts.push(t!(locale, "liststreams-live"));
+    }
+    if let Some(games) = &entry.game_filter {
+        parts.push(t!(locale, "liststreams-games", games = games.as_str()));
+    }
+    if let Some(keywords) = &entry.title_filter {
+        parts.push(t!(locale, "liststreams-keywords", keywords = keywords.as_str()));
+    }
+    if let Some(message) = &entry.custom_message {
+        // Notifications fall back to the default message, so a broken one would go unnoticed
+        if Template::parse(message).is_ok() {
+            parts.push(t!(locale, "liststreams-custom-message"));
+        } else {
+            parts.push(t!(locale, "liststreams-invalid-message"));
         }
+    }
+    if entry.paused {
+        parts.push(t!(locale, "liststreams-paused"));
+    }
+    if !entry.enabled {
+        parts.push(t!(locale, "liststreams-disabled"));
+    }
+    format!("- {}", parts.join(" · "))
+}
+
+pub async fn liststreams(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    // `!liststreams 2` pages through this channel, `!liststreams all 2` the server
+    let first = args.get("all");
+    let all = first.is_some_and(|arg| arg.eq_ignore_ascii_case("all"));
+    let page_arg = if all { args.get("page") } else { first };
+    let page = match page_arg.map(str::parse::<usize>) {
+        None => Some(1),
+        Some(Ok(page)) if page > 0 && (all || args.get("page").is_none()) => Some(page),
+        Some(_) => None,
+    };
+    let Some(page) = page else {
+        let prefix = server_prefix(&ctx).await?;
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "args-usage", usage = format!("`{}`", SPEC.usage(&prefix))))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let current_channel = ctx.message.channel.clone();
+    let server_id = match ctx.cache.get_channel(&current_channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
         _ => {
             ctx.message
                 .reply(&ctx, true)
-                .content("This command only works in server text channels.".to_string())
+                .content(t!(locale, "error-server-only"))
                 .build()
                 .await?;
             return Ok(());
         }
-    }
+    };
 
     let db = ctx.state.db.clone();
-    let added_in_channel_clone = added_in_channel.clone();
-
-    let streams: Vec<(String, Option<String>)> = tokio::task::spawn_blocking(move || {
+    let server_id_clone = server_id.clone();
+    let current_channel_clone = current_channel.clone();
+    let streams: Vec<Entry> = tokio::task::spawn_blocking(move || {
         let conn = db.get()?;
-        let mut stmt = conn.prepare(
-            "SELECT channel_name, custom_message FROM streams WHERE added_in_channel = ?1",
-        )?;
-        let rows = stmt.query_map([added_in_channel_clone], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+        let (filter, value) = if all {
+            ("server_id", server_id_clone)
+        } else {
+            ("added_in_channel", current_channel_clone)
+        };
+        // `filter` is one of the two column names above, never user input
+        let mut stmt = conn.prepare(&format!(
+            "SELECT channel_name, added_in_channel, user_id, date, custom_message, game_filter, title_filter, enabled, paused
+             FROM streams WHERE {} = ?1 ORDER BY channel_name",
+            filter
+        ))?;
+        let rows = stmt.query_map(params![value], |row| {
+            Ok(Entry {
+                channel_name: row.get(0)?,
+                added_in_channel: row.get(1)?,
+                user_id: row.get(2)?,
+                date: row.get(3)?,
+                custom_message: row.get(4)?,
+                game_filter: row.get(5)?,
+                title_filter: row.get(6)?,
+                enabled: row.get(7)?,
+                paused: row.get(8)?,
+            })
         })?;
-        Ok::<Vec<(String, Option<String>)>, Error>(rows.filter_map(|r| r.ok()).collect())
+        Ok::<Vec<Entry>, Error>(rows.filter_map(|r| r.ok()).collect())
     })
     .await
     .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
 
     if streams.is_empty() {
+        let key = if all { "liststreams-empty-server" } else { "liststreams-empty" };
         ctx.message
             .reply(&ctx, true)
-            .content("No streams configured for this channel.".to_string())
+            .content(t!(locale, key))
             .build()
             .await?;
-    } else {
-        let mut response = format!("**Streams in this channel ({}):**\n", streams.len());
-        for (channel, custom_msg) in streams {
-            if custom_msg.is_some() {
-                response.push_str(&format!("- {} (custom message)\n", channel));
-            } else {
-                response.push_str(&format!("- {}\n", channel));
+        return Ok(());
+    }
+
+    // Live markers come from Twitch, the listing is still shown without them
+    let mut logins: Vec<String> = streams.iter().map(|entry| entry.channel_name.clone()).collect();
+    logins.dedup();
+    let live: HashSet<String> = match get_streams(&logins, &ctx.state.twitch_token, &ctx.state.client_id).await {
+        Ok(live_streams) => live_streams.into_iter().map(|stream| stream.user_login).collect(),
+        Err(e) => {
+            eprintln!("✗ Failed to fetch live streams: {}", e);
+            HashSet::new()
+        }
+    };
+
+    let count = streams.len();
+    let groups: Vec<(Option<String>, Vec<String>)> = if all {
+        // One group per target channel, ordered by channel name
+        let mut targets: Vec<(String, String)> = Vec::new();
+        for entry in &streams {
+            if !targets.iter().any(|(id, _)| *id == entry.added_in_channel) {
+                let name = match ctx.cache.get_channel(&entry.added_in_channel) {
+                    Some(stoat::types::Channel::TextChannel { name, .. }) => name.to_lowercase(),
+                    _ => String::new(),
+                };
+                targets.push((entry.added_in_channel.clone(), name));
             }
         }
+        targets.sort_by(|a, b| a.1.cmp(&b.1));
+        targets
+            .into_iter()
+            .map(|(target, _)| {
+                let lines = streams
+                    .iter()
+                    .filter(|entry| entry.added_in_channel == target)
+                    .map(|entry| describe_entry(&ctx, &server_id, entry, &live, locale))
+                    .collect();
+                (Some(format!("**<#{}>**", target)), lines)
+            })
+            .collect()
+    } else {
+        let lines = streams
+            .iter()
+            .map(|entry| describe_entry(&ctx, &server_id, entry, &live, locale))
+            .collect();
+        vec![(None, lines)]
+    };
+
+    let pages = paginate(groups);
+    let Some(body) = pages.get(page - 1) else {
         ctx.message
             .reply(&ctx, true)
-            .content(response)
+            .content(t!(locale, "liststreams-no-page", pages = pages.len()))
             .build()
             .await?;
+        return Ok(());
+    };
+
+    let title = if all { "liststreams-title-all" } else { "liststreams-title" };
+    let mut response = format!("**{}**\n{}", t!(locale, title, count = count), body);
+    if pages.len() > 1 {
+        response.push_str(&t!(locale, "liststreams-page", page = page, pages = pages.len()));
+        if page < pages.len() {
+            let prefix = server_prefix(&ctx).await?;
+            let scope = if all { " all" } else { "" };
+            let command = format!("`{}liststreams{} {}`", prefix, scope, page + 1);
+            response.push(' ');
+            response.push_str(&t!(locale, "liststreams-next-page", command = command));
+        }
     }
 
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(count: usize, len: usize) -> Vec<String> {
+        (0..count).map(|i| format!("{:0>width$}", i, width = len)).collect()
+    }
+
+    #[test]
+    fn short_lists_fit_on_one_page() {
+        let pages = paginate(vec![
+            (Some("**#general**".to_string()), vec!["a".to_string(), "b".to_string()]),
+            (Some("**#live**".to_string()), vec!["c".to_string()]),
+        ]);
+        assert_eq!(pages, vec!["**#general**\na\nb\n**#live**\nc\n"]);
+    }
+
+    #[test]
+    fn ungrouped_lines_have_no_header() {
+        let pages = paginate(vec![(None, vec!["a".to_string(), "b".to_string()])]);
+        assert_eq!(pages, vec!["a\nb\n"]);
+    }
+
+    #[test]
+    fn empty_groups_are_left_out() {
+        assert!(paginate(vec![]).is_empty());
+        assert!(paginate(vec![(Some("**#general**".to_string()), vec![])]).is_empty());
+    }
+
+    #[test]
+    fn pages_stay_within_the_limit() {
+        let pages = paginate(vec![(None, lines(100, 99))]);
+        assert!(pages.len() > 1);
+        assert!(pages.iter().all(|page| page.len() <= LIST_PAGE_LENGTH));
+        // Nothing is lost or repeated across pages
+        assert_eq!(pages.concat(), lines(100, 99).iter().map(|line| format!("{}\n", line)).collect::<String>());
+    }
+
+    #[test]
+    fn header_is_repeated_when_a_group_continues() {
+        let header = "**#general**".to_string();
+        let pages = paginate(vec![(Some(header.clone()), lines(100, 99))]);
+        assert!(pages.len() > 1);
+        for page in &pages {
+            assert!(page.starts_with("**#general**\n"));
+            assert_eq!(page.matches(header.as_str()).count(), 1);
+            assert!(page.len() <= LIST_PAGE_LENGTH);
+        }
+    }
+
+    #[test]
+    fn a_new_group_starts_with_its_own_header() {
+        let pages = paginate(vec![
+            (Some("**#first**".to_string()), lines(16, 99)),
+            (Some("**#second**".to_string()), lines(2, 99)),
+        ]);
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].starts_with("**#first**\n"));
+        assert!(!pages[0].contains("**#second**"));
+        assert!(pages[1].starts_with("**#second**\n"));
+    }
+}
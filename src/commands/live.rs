@@ -0,0 +1,113 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::config::LIST_PAGE_LENGTH;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::template::escape_markdown;
+use crate::twitch::streams::get_streams;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use stoat::MessageExt;
+
+/// How long something has been running, e.g. `3d 4h`, `2h 05m` or `12m`.
+pub fn format_uptime(started_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - started_at).num_minutes().max(0);
+    if minutes >= 24 * 60 {
+        format!("{}d {}h", minutes / (24 * 60), minutes / 60 % 24)
+    } else if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+pub async fn live(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let tracked: Vec<String> = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        let mut stmt = conn.prepare("SELECT DISTINCT channel_name FROM streams WHERE server_id = ?1")?;
+        let rows = stmt.query_map(params![server_id], |row| row.get(0))?;
+        Ok::<Vec<String>, Error>(rows.filter_map(|r| r.ok()).collect())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    // Twitch is asked about every tracked channel, EventSub's online list is
+    // empty after a restart and cleared on reconnect
+    let mut streams = if tracked.is_empty() {
+        Vec::new()
+    } else {
+        match get_streams(&tracked, &ctx.state.twitch_token, &ctx.state.client_id).await {
+            Ok(streams) => streams,
+            Err(e) => {
+                eprintln!("✗ Failed to fetch live streams: {}", e);
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "live-failed"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        }
+    };
+
+    if streams.is_empty() {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "live-nobody"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    streams.sort_by_key(|stream| std::cmp::Reverse(stream.viewer_count));
+
+    let now = Utc::now();
+    let mut response = format!("**{}**\n", t!(locale, "live-title", count = streams.len()));
+    for (shown, stream) in streams.iter().enumerate() {
+        let game = if stream.game_name.is_empty() {
+            t!(locale, "live-no-game")
+        } else {
+            escape_markdown(&stream.game_name)
+        };
+        let entry = format!(
+            "🔴 **[{}](https://twitch.tv/{})** - {}\n{}\n",
+            escape_markdown(&stream.user_name),
+            stream.user_login,
+            escape_markdown(&stream.title),
+            t!(
+                locale,
+                "live-details",
+                game = game,
+                uptime = format_uptime(stream.started_at, now),
+                viewers = t!(locale, "embed-viewers", count = stream.viewer_count)
+            )
+        );
+        if response.len() + entry.len() > LIST_PAGE_LENGTH {
+            response.push_str(&t!(locale, "live-more", count = streams.len() - shown));
+            break;
+        }
+        response.push_str(&entry);
+    }
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use crate::commands::CmdCtx;
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::twitch::types::HelixUser;
+use crate::twitch::validation::{
+    ChannelInput, get_login_by_id, get_users, is_valid_login, parse_channel_input,
+};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::collections::HashSet;
+use stoat::{GlobalCache, MessageExt};
+use stoat::types::{Channel, Server};
+
+/// Finds a server role from a mention (`<%ID>`), a raw ID or its name.
+pub fn find_role(server: &Server, value: &str) -> Option<String> {
+    let id = value.trim_start_matches("<%").trim_end_matches('>');
+    server
+        .roles
+        .iter()
+        .find(|(role_id, role)| role_id.as_str() == id || role.name.eq_ignore_ascii_case(value))
+        .map(|(role_id, _)| role_id.clone())
+}
+
+/// Finds a text channel in the given server from a mention (`<#ID>`) or a raw ID.
+pub fn find_text_channel(cache: &GlobalCache, server_id: &str, value: &str) -> Option<String> {
+    let id = value.trim_start_matches("<#").trim_end_matches('>');
+    match cache.get_channel(id)? {
+        Channel::TextChannel { id, server, .. } if server == server_id => Some(id),
+        _ => None,
+    }
+}
+
+/// Extracts a user ID from a mention (`<@ID>`) or a raw ID.
+pub fn user_id(value: &str) -> &str {
+    value.trim_start_matches("<@").trim_end_matches('>')
+}
+
+/// Resolves a channel argument (login, Twitch URL or broadcaster ID) to a
+/// Twitch login, replying with the reason if it names no channel.
+pub async fn twitch_login(ctx: &CmdCtx, input: &str, locale: Locale) -> Result<Option<String>, Error> {
+    let error = match parse_channel_input(input) {
+        Some(ChannelInput::Login(login)) => return Ok(Some(login)),
+        Some(ChannelInput::Id(id)) => {
+            match get_login_by_id(&id, &ctx.state.twitch_token, &ctx.state.client_id).await {
+                Ok(Some(login)) => return Ok(Some(login)),
+                // Logins made of digits only exist too
+                Ok(None) if is_valid_login(&id) => return Ok(Some(id)),
+                Ok(None) => t!(locale, "twitch-channel-not-found", channel = id.as_str()),
+                Err(e) => {
+                    eprintln!("✗ Failed to look up Twitch user {}: {}", id, e);
+                    t!(locale, "twitch-lookup-failed")
+                }
+            }
+        }
+        None => t!(locale, "invalid-channel-name"),
+    };
+
+    ctx.message
+        .reply(ctx, true)
+        .content(error)
+        .build()
+        .await?;
+    Ok(None)
+}
+
+/// Finds a subscription to `channel_name` in a server, preferring the one
+/// notifying `channel_id`. Returns its row ID and the user who owns it.
+/// Streams added by a followed team are left out, the team sync owns those.
+pub fn find_subscription(
+    conn: &Connection,
+    channel_name: &str,
+    server_id: &str,
+    channel_id: &str,
+) -> Result<Option<(i64, String)>, Error> {
+    Ok(conn
+        .query_row(
+            "SELECT id, user_id FROM streams
+             WHERE channel_name = ?1 AND server_id = ?2 AND team_id IS NULL
+             ORDER BY added_in_channel = ?3 DESC, id LIMIT 1",
+            params![channel_name, server_id, channel_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?)
+}
+
+/// The name of a followed team that announces `channel_name` in a server.
+pub fn find_managing_team(conn: &Connection, channel_name: &str, server_id: &str) -> Result<Option<String>, Error> {
+    Ok(conn
+        .query_row(
+            "SELECT teams.team_name FROM streams JOIN teams ON teams.id = streams.team_id
+             WHERE streams.channel_name = ?1 AND streams.server_id = ?2
+             ORDER BY teams.id LIMIT 1",
+            params![channel_name, server_id],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// Replies that `channel_name` has no subscription to act on, pointing at
+/// `!removeteam` if a followed team announces it instead.
+pub async fn reply_not_found(
+    ctx: &CmdCtx,
+    channel_name: &str,
+    server_id: &str,
+    not_found: String,
+    locale: Locale,
+) -> Result<(), Error> {
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.to_string();
+    let server_id = server_id.to_string();
+    let team = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        find_managing_team(&conn, &channel_name_clone, &server_id)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let content = match team {
+        Some(team) => t!(locale, "stream-managed-by-team", channel = channel_name, team = team.as_str()),
+        None => not_found,
+    };
+    ctx.message
+        .reply(ctx, true)
+        .content(content)
+        .build()
+        .await?;
+    Ok(())
+}
+
+/// Describes a stored role for display, e.g. `@Live pings`.
+pub fn describe_role(server: &Server, role_id: &str, locale: Locale) -> String {
+    match server.roles.get(role_id) {
+        Some(role) => format!("@{}", role.name),
+        None => t!(locale, "role-deleted", role = role_id),
+    }
+}
+
+/// A member's name without mentioning them: server nickname, display name,
+/// username, or the raw ID if the user isn't cached.
+pub fn describe_user(cache: &GlobalCache, server_id: &str, user_id: &str) -> String {
+    if let Some(nickname) = cache.get_member(server_id, user_id).and_then(|m| m.nickname) {
+        return nickname;
+    }
+    match cache.get_user(user_id) {
+        Some(user) => user.display_name.unwrap_or(user.username),
+        None => user_id.to_string(),
+    }
+}
+
+/// Splits a bulk argument list on whitespace and commas, dropping repeats.
+pub fn split_channels(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|input| !input.is_empty())
+        .filter(|input| seen.insert(input.to_lowercase()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Looks up every parsed channel with batched Helix requests. Numeric input
+/// is tried as a broadcaster ID first, then as a login. Replies and returns
+/// `None` if Twitch can't be reached.
+pub async fn resolve_channels(
+    ctx: &CmdCtx,
+    parsed: &[Option<ChannelInput>],
+    locale: Locale,
+) -> Result<Option<Vec<Option<HelixUser>>>, Error> {
+    let mut logins = Vec::new();
+    let mut ids = Vec::new();
+    for input in parsed.iter().flatten() {
+        match input {
+            ChannelInput::Login(login) => logins.push(login.clone()),
+            ChannelInput::Id(id) => {
+                ids.push(id.clone());
+                if is_valid_login(id) {
+                    logins.push(id.clone());
+                }
+            }
+        }
+    }
+    if logins.is_empty() && ids.is_empty() {
+        return Ok(Some(vec![None; parsed.len()]));
+    }
+
+    let users = match get_users(&logins, &ids, &ctx.state.twitch_token, &ctx.state.client_id).await {
+        Ok(users) => users,
+        Err(e) => {
+            eprintln!("✗ Failed to look up Twitch users: {}", e);
+            ctx.message
+                .reply(ctx, true)
+                .content(t!(locale, "twitch-lookup-failed"))
+                .build()
+                .await?;
+            return Ok(None);
+        }
+    };
+
+    Ok(Some(
+        parsed
+            .iter()
+            .map(|input| match input {
+                Some(ChannelInput::Login(login)) => users.iter().find(|u| u.login == *login),
+                Some(ChannelInput::Id(id)) => users
+                    .iter()
+                    .find(|u| u.id == *id)
+                    .or_else(|| users.iter().find(|u| u.login == *id)),
+                None => None,
+            }
+            .cloned())
+            .collect(),
+    ))
+}
@@ -1,18 +1,61 @@
+mod addcategory;
 mod addstream;
+mod addstreams;
+mod addteam;
+mod args;
+mod attachments;
+mod editstream;
+mod exportstreams;
 mod helpstream;
+mod importstreams;
+mod live;
 mod liststreams;
+mod lookup;
+mod movestream;
+mod permissions;
+mod removecategory;
 mod removestream;
+mod removestreams;
+mod removeteam;
+mod streambotstatus;
+mod streamconfig;
+mod streamfile;
+mod streamquota;
+mod table;
+mod teststream;
+mod transferstream;
 
+use crate::config::DEFAULT_PREFIX;
+use crate::i18n::{Locale, t};
+use crate::settings::load_server_settings;
 use crate::{error::Error, state::State};
+use permissions::CanManageStreams;
+use stoat::MessageExt;
 use stoat::async_trait;
 use stoat::commands::{
     Command, CommandEventHandler, CommandHandler as StoatCommandHandler, Context as CommandContext,
 };
 
+pub use addcategory::addcategory;
 pub use addstream::addstream;
+pub use addstreams::addstreams;
+pub use addteam::addteam;
+pub use editstream::editstream;
+pub use exportstreams::exportstreams;
 pub use helpstream::helpstream;
+pub use importstreams::importstreams;
+pub use live::live;
 pub use liststreams::liststreams;
+pub use movestream::movestream;
+pub use removecategory::removecategory;
 pub use removestream::removestream;
+pub use removestreams::removestreams;
+pub use removeteam::removeteam;
+pub use streambotstatus::streambotstatus;
+pub use streamconfig::streamconfig;
+pub use streamquota::streamquota;
+pub use teststream::teststream;
+pub use transferstream::transferstream;
 
 pub type CmdCtx = CommandContext<Error, State>;
 pub type CommandHandler = StoatCommandHandler<Commands>;
@@ -25,16 +68,138 @@ impl CommandEventHandler for Commands {
     type State = State;
     type Error = Error;
 
-    async fn get_prefix(&self, _ctx: CmdCtx) -> Result<Vec<String>, Error> {
-        Ok(vec!["!".to_string()])
+    async fn get_prefix(&self, ctx: CmdCtx) -> Result<Vec<String>, Error> {
+        let mut prefixes = Vec::new();
+        // Mentioning the bot always works, so a forgotten prefix can be changed
+        if let Some(bot_id) = ctx.cache.get_current_user_id() {
+            prefixes.push(format!("<@{}> ", bot_id));
+            prefixes.push(format!("<@{}>", bot_id));
+        }
+        prefixes.push(server_prefix(&ctx).await?);
+        Ok(prefixes)
     }
+
+    async fn error(&self, ctx: CmdCtx, error: Error) -> Result<(), Error> {
+        match error {
+            Error::StoatError(stoat::Error::CheckFailure) => {
+                let locale = server_locale(&ctx).await?;
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "permission-denied"))
+                    .build()
+                    .await?;
+            }
+            e => eprintln!("✗ Command error: {}", e),
+        }
+
+        Ok(())
+    }
+}
+
+/// The message text after the prefix it was invoked with, so the command
+/// name comes first whether it was typed as `!name`, a custom prefix or after
+/// mentioning the bot.
+pub fn command_text(ctx: &CmdCtx) -> String {
+    let content = ctx.message.content.as_deref().unwrap_or_default();
+    let prefix_len = ctx.prefix.as_ref().map_or(0, |prefix| prefix.len());
+    content.get(prefix_len..).unwrap_or_default().to_string()
+}
+
+/// The command prefix of the server a message was sent in. Prefixes are
+/// looked up for every message, so they are cached in `State`.
+pub async fn server_prefix(ctx: &CmdCtx) -> Result<String, Error> {
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => return Ok(DEFAULT_PREFIX.to_string()),
+    };
+
+    if let Some(prefix) = ctx.state.prefixes.read().get(&server_id) {
+        return Ok(prefix.clone());
+    }
+
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let prefix = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        Ok::<_, Error>(load_server_settings(&conn, &server_id_clone)?.prefix().to_string())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    ctx.state.prefixes.write().insert(server_id, prefix.clone());
+    Ok(prefix)
+}
+
+/// The language configured for the server a command was used in. Outside
+/// servers, and for servers without a setting, replies are in English.
+pub async fn server_locale(ctx: &CmdCtx) -> Result<Locale, Error> {
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => return Ok(Locale::default()),
+    };
+
+    let db = ctx.state.db.clone();
+    tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        Ok(load_server_settings(&conn, &server_id)?.locale())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
 }
 
 pub fn create_handler(state: State) -> CommandHandler {
     StoatCommandHandler::new(Commands, state).register(vec![
-        Command::new("addstream", addstream).description("Add a Twitch channel to monitor"),
-        Command::new("removestream", removestream).description("Remove a monitored channel"),
+        Command::new("addstream", addstream)
+            .description("Add a Twitch channel to monitor")
+            .check(CanManageStreams),
+        Command::new("addstreams", addstreams)
+            .description("Add several Twitch channels at once")
+            .check(CanManageStreams),
+        Command::new("editstream", editstream)
+            .description("Change a monitored channel's settings")
+            .check(CanManageStreams),
+        Command::new("removestream", removestream)
+            .description("Remove a monitored channel")
+            .check(CanManageStreams),
+        Command::new("removestreams", removestreams)
+            .description("Remove several monitored channels at once")
+            .check(CanManageStreams),
+        Command::new("addteam", addteam)
+            .description("Monitor every member of a Twitch team")
+            .check(CanManageStreams),
+        Command::new("removeteam", removeteam)
+            .description("Stop monitoring a Twitch team")
+            .check(CanManageStreams),
+        Command::new("addcategory", addcategory)
+            .description("Announce new streams in a Twitch category")
+            .check(CanManageStreams),
+        Command::new("removecategory", removecategory)
+            .description("Stop announcing a Twitch category")
+            .check(CanManageStreams),
+        Command::new("movestream", movestream)
+            .description("Send a monitored channel's notifications somewhere else")
+            .check(CanManageStreams),
+        Command::new("transferstream", transferstream)
+            .description("Give a monitored channel to another member")
+            .check(CanManageStreams),
+        Command::new("exportstreams", exportstreams)
+            .description("Export the server's streams as a JSON or CSV file")
+            .check(CanManageStreams),
+        Command::new("importstreams", importstreams)
+            .description("Import streams from an attached JSON or CSV file")
+            .check(CanManageStreams),
+        Command::new("live", live).description("Show who is live right now"),
         Command::new("liststreams", liststreams).description("List monitored channels"),
+        Command::new("streamconfig", streamconfig)
+            .description("Show or change server stream settings")
+            .check(CanManageStreams),
+        Command::new("teststream", teststream)
+            .description("Preview or test-send a stream notification")
+            .check(CanManageStreams),
+        Command::new("streambotstatus", streambotstatus)
+            .description("Show the bot's health (server admins and bot operator)"),
+        Command::new("streamquota", streamquota)
+            .description("Override a server's stream limits (bot operator only)"),
         Command::new("helpstream", helpstream).description("Show available commands"),
     ])
 }
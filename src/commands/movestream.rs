@@ -0,0 +1,194 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{find_subscription, find_text_channel, reply_not_found, twitch_login};
+use crate::commands::permissions::{bot_can_send, can_manage_all_streams};
+use crate::error::Error;
+use crate::i18n::t;
+use crate::quota::{QuotaExceeded, Quotas, channel_usage};
+use crate::settings::load_server_settings;
+use rusqlite::{OptionalExtension, params};
+use stoat::MessageExt;
+
+enum MoveOutcome {
+    Moved,
+    AlreadyThere,
+    Duplicate,
+    ChannelFull,
+}
+
+const SPEC: Spec = Spec {
+    name: "movestream",
+    required: &["channel", "#target|here"],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn movestream(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
+    };
+    let target_arg = args.get("#target|here").unwrap_or_default();
+    let current_channel = ctx.message.channel.clone();
+
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let target = if target_arg.eq_ignore_ascii_case("here") {
+        Some(current_channel.clone())
+    } else {
+        find_text_channel(&ctx.cache, &server_id, target_arg)
+    };
+    let Some(target) = target else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "text-channel-not-found", channel = target_arg))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let server_obj = ctx.cache.get_server(&server_id).unwrap();
+    let target_channel = ctx.cache.get_channel(&target).unwrap();
+    if !bot_can_send(&ctx, &server_obj, &target_channel).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "movestream-cannot-send", target = target.as_str()))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.clone();
+    let (subscription, settings) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let subscription = find_subscription(&conn, &channel_name_clone, &server_id, &current_channel)?;
+        Ok((subscription, load_server_settings(&conn, &server_id)?))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let (stream_id, owner) = match subscription {
+        Some(s) => s,
+        None => {
+            let not_found = t!(locale, "stream-not-in-server", channel = channel_name.as_str());
+            reply_not_found(&ctx, &channel_name, &server_obj.id, not_found, locale).await?;
+            return Ok(());
+        }
+    };
+
+    if owner != ctx.message.author && !can_manage_all_streams(&ctx).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "movestream-not-yours"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let max_per_channel = Quotas::for_server(&ctx.state.config, &settings).per_channel;
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.clone();
+    let target_clone = target.clone();
+    let move_result = tokio::task::spawn_blocking(move || -> Result<MoveOutcome, Error> {
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+
+        let current: String = tx.query_row(
+            "SELECT added_in_channel FROM streams WHERE id = ?1",
+            params![stream_id],
+            |row| row.get(0),
+        )?;
+        if current == target_clone {
+            return Ok(MoveOutcome::AlreadyThere);
+        }
+
+        // Any subscription to the same streamer in the target channel would
+        // post twice, not just the one the UNIQUE constraint catches
+        let existing = tx
+            .query_row(
+                "SELECT id FROM streams WHERE channel_name = ?1 AND added_in_channel = ?2",
+                params![channel_name_clone, target_clone],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()?;
+        if existing.is_some() {
+            return Ok(MoveOutcome::Duplicate);
+        }
+
+        let in_target = channel_usage(&tx, &target_clone)?;
+        if in_target >= max_per_channel {
+            return Ok(MoveOutcome::ChannelFull);
+        }
+
+        match tx.execute(
+            "UPDATE streams SET added_in_channel = ?1 WHERE id = ?2",
+            params![target_clone, stream_id],
+        ) {
+            Ok(_) => {}
+            Err(e) if e.to_string().contains("UNIQUE constraint failed") => {
+                return Ok(MoveOutcome::Duplicate);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        tx.commit()?;
+        Ok(MoveOutcome::Moved)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+
+    let response = match move_result {
+        Ok(MoveOutcome::Moved) => {
+            println!("✓ Moved stream {} to channel {}", channel_name, target);
+            t!(
+                locale,
+                "movestream-moved",
+                channel = channel_name.as_str(),
+                target = target.as_str()
+            )
+        }
+        Ok(MoveOutcome::AlreadyThere) => t!(
+            locale,
+            "movestream-already-there",
+            channel = channel_name.as_str(),
+            target = target.as_str()
+        ),
+        Ok(MoveOutcome::Duplicate) => t!(
+            locale,
+            "movestream-duplicate",
+            channel = channel_name.as_str(),
+            target = target.as_str()
+        ),
+        Ok(MoveOutcome::ChannelFull) => QuotaExceeded::Channel(max_per_channel).message(locale),
+        Err(e) => {
+            eprintln!("✗ Error moving stream: {}", e);
+            t!(locale, "movestream-failed")
+        }
+    };
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
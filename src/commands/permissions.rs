@@ -0,0 +1,85 @@
+use crate::commands::CmdCtx;
+use crate::error::Error;
+use crate::settings::load_server_settings;
+use crate::state::State;
+use stoat::async_trait;
+use stoat::commands::Check;
+use std::borrow::Cow;
+use stoat::permissions::{calculate_channel_permissions, user_permissions_query};
+use stoat::types::{Channel, ChannelPermission, Server};
+
+/// Allows a command for the server owner, members with Manage Server or
+/// Manage Channel, and members with the server's stream manager role.
+///
+/// Outside servers the check passes, so commands can reply with their own
+/// "server text channels only" message.
+pub struct CanManageStreams;
+
+#[async_trait]
+impl Check<Error, State> for CanManageStreams {
+    async fn run(&self, ctx: CmdCtx) -> Result<bool, Error> {
+        let Ok(server) = ctx.get_current_server() else {
+            return Ok(true);
+        };
+
+        if can_manage_all_streams(&ctx).await {
+            return Ok(true);
+        }
+
+        let db = ctx.state.db.clone();
+        let server_id = server.id.clone();
+        let manager_role = tokio::task::spawn_blocking(move || {
+            let conn = db.get()?;
+            Ok::<_, Error>(load_server_settings(&conn, &server_id)?.manager_role)
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+        Ok(match (manager_role, ctx.get_member().await) {
+            (Some(role), Ok(member)) => member.roles.contains(&role),
+            _ => false,
+        })
+    }
+}
+
+/// Members who may change streams added by someone else: server admins and
+/// members with Manage Channel. Holders of the stream manager role only
+/// manage their own.
+pub async fn can_manage_all_streams(ctx: &CmdCtx) -> bool {
+    is_server_admin(ctx).await
+        || ctx
+            .get_author_channel_permissions()
+            .await
+            .has_channel_permission(ChannelPermission::ManageChannel)
+}
+
+/// The server owner or a member with Manage Server.
+pub async fn is_server_admin(ctx: &CmdCtx) -> bool {
+    let Ok(server) = ctx.get_current_server() else {
+        return false;
+    };
+
+    server.owner == ctx.message.author
+        || ctx
+            .get_author_server_permissions()
+            .await
+            .has_channel_permission(ChannelPermission::ManageServer)
+}
+
+/// Whether the bot itself may post in a channel of the given server.
+pub async fn bot_can_send(ctx: &CmdCtx, server: &Server, channel: &Channel) -> bool {
+    let Some(bot) = ctx.cache.get_current_user() else {
+        return false;
+    };
+    let Ok(member) = ctx.http.fetch_member(&server.id, &bot.id).await else {
+        return false;
+    };
+
+    let mut query = user_permissions_query(ctx.cache.clone(), ctx.http.clone(), Cow::Owned(bot))
+        .channel(Cow::Borrowed(channel))
+        .server(Cow::Borrowed(server))
+        .member(Cow::Owned(member));
+    calculate_channel_permissions(&mut query)
+        .await
+        .has_channel_permission(ChannelPermission::SendMessage)
+}
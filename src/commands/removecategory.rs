@@ -0,0 +1,96 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::template::escape_markdown;
+use crate::twitch::categories::load_categories;
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "removecategory",
+    required: &[],
+    optional: &[],
+    rest: Some("category"),
+    rest_required: true,
+    options: &[],
+};
+
+pub async fn removecategory(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    let game_arg = args.rest().unwrap_or_default().trim().to_string();
+
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let categories = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_categories(&conn, Some(&server_id))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    // Categories are matched by name or ID, as shown by !addcategory
+    let Some(category) = categories
+        .into_iter()
+        .find(|category| category.game_name.eq_ignore_ascii_case(&game_arg) || category.game_id == game_arg)
+    else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "removecategory-not-found", game = game_arg.as_str()))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    // Anyone can remove their own categories, managers can remove any in the server
+    if category.user_id != ctx.message.author && !can_manage_all_streams(&ctx).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "removecategory-not-yours"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let category_id = category.id;
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM category_seen WHERE category_id = ?1", params![category_id])?;
+        tx.execute("DELETE FROM categories WHERE id = ?1", params![category_id])?;
+        tx.commit()?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    println!("✓ Stopped following category {} in server {}", category.game_name, category.server_id);
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(t!(
+            locale,
+            "removecategory-removed",
+            game = escape_markdown(&category.game_name)
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
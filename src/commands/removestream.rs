@@ -1,87 +1,86 @@
-use crate::commands::CmdCtx;
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{find_subscription, reply_not_found, twitch_login};
+use crate::commands::permissions::can_manage_all_streams;
 use crate::error::Error;
+use crate::i18n::t;
 use crate::twitch::unsubscribe_single_channel;
 use rusqlite::params;
 use stoat::MessageExt;
 
+const SPEC: Spec = Spec {
+    name: "removestream",
+    required: &["channel"],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
 pub async fn removestream(ctx: CmdCtx) -> Result<(), Error> {
-    let message_text = ctx.message.content.as_ref().unwrap_or(&String::new()).clone();
-    let parts: Vec<&str> = message_text.split_whitespace().collect();
-    
-    if parts.len() < 2 {
-        ctx.message
-            .reply(&ctx, true)
-            .content("Usage: !removestream <channel_name>".to_string())
-            .build()
-            .await?;
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
         return Ok(());
-    }
+    };
     
-    let channel_name = parts[1].to_lowercase();
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
+    };
     let user = match ctx.message.user.as_ref() {
         Some(u) => u,
         None => {
             ctx.message
                 .reply(&ctx, true)
-                .content("Unable to identify user.".to_string())
+                .content(t!(locale, "error-no-user"))
                 .build()
                 .await?;
             return Ok(());
         }
     };
     let user_id = user.id.clone();
-    let added_in_channel = ctx.message.channel.clone();
+    let current_channel = ctx.message.channel.clone();
     
-    // Check if user is server owner (for TextChannel only)
-    let channel = ctx.cache.get_channel(&added_in_channel).unwrap();
-    match channel {
-        stoat::types::Channel::TextChannel { server, .. } => {
-            let server_obj = ctx.cache.get_server(&server).unwrap();
-            if server_obj.owner != user_id {
-                ctx.message
-                    .reply(&ctx, true)
-                    .content("You must be the server owner to remove streams here.".to_string())
-                    .build()
-                    .await?;
-                return Ok(());
-            }
-        }
+    // Streams can only be removed from server text channels
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
         _ => {
             ctx.message
                 .reply(&ctx, true)
-                .content("This command only works in server text channels.".to_string())
+                .content(t!(locale, "error-server-only"))
                 .build()
                 .await?;
             return Ok(());
         }
-    }
+    };
     
     let db = ctx.state.db.clone();
     let channel_name_clone = channel_name.clone();
-    let user_id_clone = user_id.clone();
-    let added_in_channel_clone = added_in_channel.clone();
+    let server_id_clone = server_id.clone();
     
-    let delete_result = tokio::task::spawn_blocking(move || -> Result<bool, Error> {
+    let subscription = tokio::task::spawn_blocking(move || {
         let conn = db.get()?;
-        
-        // Check if stream exists and belongs to this user
-        let mut stmt = conn.prepare("SELECT user_id FROM streams WHERE channel_name = ?1 AND added_in_channel = ?2")?;
-        let owner_id: Result<String, rusqlite::Error> = stmt.query_row(params![channel_name_clone, added_in_channel_clone], |row| row.get(0));
-        
-        match owner_id {
-            Ok(owner) if owner == user_id_clone => {
-                // User owns this stream, delete it
-                let rows = conn.execute(
-                    "DELETE FROM streams WHERE channel_name = ?1 AND added_in_channel = ?2 AND user_id = ?3",
-                    params![channel_name_clone, added_in_channel_clone, user_id_clone],
-                )?;
+        find_subscription(&conn, &channel_name_clone, &server_id_clone, &current_channel)
+    }).await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+    
+    // Anyone can remove their own streams, managers can remove any in the server
+    let delete_result = match subscription {
+        Some((_, owner)) if owner != user_id && !can_manage_all_streams(&ctx).await => {
+            Err(Error::DatabaseError("Not owner".to_string()))
+        }
+        Some((stream_id, _)) => {
+            let db = ctx.state.db.clone();
+            tokio::task::spawn_blocking(move || -> Result<bool, Error> {
+                let conn = db.get()?;
+                let rows = conn.execute("DELETE FROM streams WHERE id = ?1", params![stream_id])?;
                 Ok(rows > 0)
-            }
-            Ok(_) => Err(Error::DatabaseError("Not owner".to_string())),
-            Err(_) => Err(Error::DatabaseError("Not found".to_string())),
+            }).await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
         }
-    }).await
-    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+        None => Err(Error::DatabaseError("Not found".to_string())),
+    };
     
     match delete_result {
         Ok(true) => {
@@ -108,36 +107,33 @@ pub async fn removestream(ctx: CmdCtx) -> Result<(), Error> {
             println!("✓ Removed stream: {}", channel_name);
             ctx.message
                 .reply(&ctx, true)
-                .content(format!("Removed channel: {}", channel_name))
+                .content(t!(locale, "removestream-removed", channel = channel_name.as_str()))
                 .build()
                 .await?;
         }
         Ok(false) => {
             ctx.message
                 .reply(&ctx, true)
-                .content("Stream not found.".to_string())
+                .content(t!(locale, "removestream-not-found"))
                 .build()
                 .await?;
         }
         Err(e) if e.to_string().contains("Not owner") => {
             ctx.message
                 .reply(&ctx, true)
-                .content("You can only remove streams you added.".to_string())
+                .content(t!(locale, "removestream-not-yours"))
                 .build()
                 .await?;
         }
         Err(e) if e.to_string().contains("Not found") => {
-            ctx.message
-                .reply(&ctx, true)
-                .content(format!("Stream {} not found in this channel.", channel_name))
-                .build()
-                .await?;
+            let not_found = t!(locale, "stream-not-in-server", channel = channel_name.as_str());
+            reply_not_found(&ctx, &channel_name, &server_id, not_found, locale).await?;
         }
         Err(e) => {
             eprintln!("✗ Error removing stream: {}", e);
             ctx.message
                 .reply(&ctx, true)
-                .content("Failed to remove stream.".to_string())
+                .content(t!(locale, "removestream-failed"))
                 .build()
                 .await?;
         }
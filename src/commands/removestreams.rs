@@ -0,0 +1,153 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{find_managing_team, find_subscription, resolve_channels, split_channels};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::commands::table::code_table_within;
+use crate::config::{LIST_PAGE_LENGTH, MAX_CONCURRENT_SUBSCRIBES};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::twitch::unsubscribe_single_channel;
+use crate::twitch::validation::{ChannelInput, parse_channel_input};
+use futures_util::StreamExt;
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "removestreams",
+    required: &[],
+    optional: &[],
+    rest: Some("channels"),
+    rest_required: true,
+    options: &[],
+};
+
+enum Outcome {
+    Removed,
+    NotFound,
+    NotYours,
+    /// Announced because the server follows this team
+    Team(String),
+}
+
+impl Outcome {
+    fn label(&self, locale: Locale) -> String {
+        match self {
+            Outcome::Removed => t!(locale, "bulk-removed"),
+            Outcome::NotFound => t!(locale, "bulk-not-found"),
+            Outcome::NotYours => t!(locale, "bulk-not-yours"),
+            Outcome::Team(team) => t!(locale, "bulk-team", team = team.as_str()),
+        }
+    }
+}
+
+pub async fn removestreams(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    let inputs = split_channels(args.rest().unwrap_or_default());
+    let parsed: Vec<Option<ChannelInput>> = inputs.iter().map(|i| parse_channel_input(i)).collect();
+
+    let user_id = ctx.message.author.clone();
+    let current_channel = ctx.message.channel.clone();
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Logins are matched against the database directly, only IDs need Twitch
+    let logins: Vec<Option<String>> = if parsed.iter().any(|p| matches!(p, Some(ChannelInput::Id(_)))) {
+        let Some(resolved) = resolve_channels(&ctx, &parsed, locale).await? else {
+            return Ok(());
+        };
+        resolved.into_iter().map(|user| user.map(|u| u.login)).collect()
+    } else {
+        parsed
+            .into_iter()
+            .map(|p| match p {
+                Some(ChannelInput::Login(login)) => Some(login),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let manages_all = can_manage_all_streams(&ctx).await;
+
+    let db = ctx.state.db.clone();
+    let (outcomes, unused) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+        let mut outcomes = Vec::new();
+        let mut removed = Vec::new();
+        for login in &logins {
+            let outcome = match login {
+                None => Outcome::NotFound,
+                Some(login) => match find_subscription(&tx, login, &server_id, &current_channel)? {
+                    None => match find_managing_team(&tx, login, &server_id)? {
+                        Some(team) => Outcome::Team(team),
+                        None => Outcome::NotFound,
+                    },
+                    Some((_, owner)) if owner != user_id && !manages_all => Outcome::NotYours,
+                    Some((stream_id, _)) => {
+                        tx.execute("DELETE FROM streams WHERE id = ?1", params![stream_id])?;
+                        removed.push(login.clone());
+                        Outcome::Removed
+                    }
+                },
+            };
+            outcomes.push(outcome);
+        }
+
+        // Channels no other server follows any more
+        let mut unused = Vec::new();
+        for login in removed {
+            let count: i64 = tx.query_row(
+                "SELECT COUNT(*) FROM streams WHERE channel_name = ?1",
+                params![login],
+                |row| row.get(0),
+            )?;
+            if count == 0 && !unused.contains(&login) {
+                unused.push(login);
+            }
+        }
+        tx.commit()?;
+        Ok((outcomes, unused))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let removed = outcomes.iter().filter(|o| matches!(o, Outcome::Removed)).count();
+    if removed > 0 {
+        println!("✓ Removed {} streams in bulk", removed);
+    }
+
+    let state = &ctx.state;
+    futures_util::stream::iter(unused)
+        .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |login| async move {
+            unsubscribe_single_channel(&login, &state.twitch_token, &state.client_id).await;
+        })
+        .await;
+
+    let header = [t!(locale, "bulk-channel"), t!(locale, "bulk-result")];
+    let rows: Vec<Vec<String>> = inputs
+        .iter()
+        .zip(&outcomes)
+        .map(|(input, outcome)| vec![input.clone(), outcome.label(locale)])
+        .collect();
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(code_table_within(&header, &rows, LIST_PAGE_LENGTH, locale))
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::config::MAX_CONCURRENT_SUBSCRIBES;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::template::escape_markdown;
+use crate::twitch::teams::{load_teams, parse_team_input};
+use crate::twitch::unsubscribe_single_channel;
+use futures_util::StreamExt;
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "removeteam",
+    required: &["team"],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn removeteam(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let team_arg = args.get("team").unwrap_or_default();
+    let server_id = match ctx.cache.get_channel(&ctx.message.channel) {
+        Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let db = ctx.state.db.clone();
+    let teams = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_teams(&conn, Some(&server_id))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let team_name = parse_team_input(team_arg);
+    let Some(team) = teams.into_iter().find(|team| Some(&team.team_name) == team_name.as_ref()) else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "removeteam-not-found", team = team_arg))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    // Anyone can remove their own teams, managers can remove any in the server
+    if team.user_id != ctx.message.author && !can_manage_all_streams(&ctx).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "removeteam-not-yours"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let team_id = team.id;
+    let (removed, unused) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+        let logins: Vec<String> = {
+            let mut stmt = tx.prepare("SELECT channel_name FROM streams WHERE team_id = ?1")?;
+            let rows = stmt.query_map(params![team_id], |row| row.get(0))?;
+            rows.filter_map(|r| r.ok()).collect()
+        };
+        tx.execute("DELETE FROM streams WHERE team_id = ?1", params![team_id])?;
+        tx.execute("DELETE FROM teams WHERE id = ?1", params![team_id])?;
+
+        // Channels no other server follows any more
+        let mut unused = Vec::new();
+        for login in &logins {
+            let count: i64 = tx.query_row(
+                "SELECT COUNT(*) FROM streams WHERE channel_name = ?1",
+                params![login],
+                |row| row.get(0),
+            )?;
+            if count == 0 {
+                unused.push(login.clone());
+            }
+        }
+        tx.commit()?;
+        Ok((logins.len(), unused))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    println!("✓ Removed team {} with {} streams", team.team_name, removed);
+
+    let state = &ctx.state;
+    futures_util::stream::iter(unused)
+        .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |login| async move {
+            unsubscribe_single_channel(&login, &state.twitch_token, &state.client_id).await;
+        })
+        .await;
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(t!(
+            locale,
+            "removeteam-removed",
+            team = escape_markdown(&team.display_name),
+            count = removed
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use crate::commands::live::format_uptime;
+use crate::commands::permissions::is_server_admin;
+use crate::commands::{CmdCtx, server_locale};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::twitch::subscription::list_subscriptions;
+use crate::twitch::validation::{get_users, validate_token};
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+use std::collections::HashSet;
+use stoat::MessageExt;
+
+/// "5m ago", or "never" if it hasn't happened yet.
+fn describe_since(time: Option<DateTime<Utc>>, now: DateTime<Utc>, locale: Locale) -> String {
+    match time {
+        Some(time) => t!(locale, "streambotstatus-ago", age = format_uptime(time, now)),
+        None => t!(locale, "streambotstatus-never"),
+    }
+}
+
+pub async fn streambotstatus(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+
+    // The operator sees every server, server admins only their own
+    let is_operator = ctx.state.config.operator_id.as_deref() == Some(ctx.message.author.as_str());
+    let server = if is_operator {
+        None
+    } else {
+        let Ok(server) = ctx.get_current_server() else {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        };
+        if !is_server_admin(&ctx).await {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "streambotstatus-admins-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Some(server)
+    };
+
+    let db = ctx.state.db.clone();
+    let server_id = server.as_ref().map(|s| s.id.clone());
+    let (tracked, pending, failed) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let tracked: Vec<String> = match &server_id {
+            Some(server_id) => {
+                let mut stmt = conn.prepare("SELECT DISTINCT channel_name FROM streams WHERE server_id = ?1")?;
+                let rows = stmt.query_map(params![server_id], |row| row.get(0))?;
+                rows.filter_map(|r| r.ok()).collect()
+            }
+            None => {
+                let mut stmt = conn.prepare("SELECT DISTINCT channel_name FROM streams")?;
+                let rows = stmt.query_map([], |row| row.get(0))?;
+                rows.filter_map(|r| r.ok()).collect()
+            }
+        };
+        // Outbox rows only know their channel, so a server's share goes through its streams
+        let count_outbox = |state: &str| -> Result<i64, Error> {
+            Ok(match &server_id {
+                Some(server_id) => conn.query_row(
+                    "SELECT COUNT(*) FROM outbox WHERE state = ?1
+                     AND channel_id IN (SELECT added_in_channel FROM streams WHERE server_id = ?2)",
+                    params![state, server_id],
+                    |row| row.get(0),
+                )?,
+                None => conn.query_row(
+                    "SELECT COUNT(*) FROM outbox WHERE state = ?1",
+                    params![state],
+                    |row| row.get(0),
+                )?,
+            })
+        };
+        Ok((tracked, count_outbox("pending")?, count_outbox("failed")?))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let state = &ctx.state;
+    let now = Utc::now();
+    let status = state.eventsub.read().clone();
+
+    let eventsub = match status.session_started.filter(|_| status.connected) {
+        Some(started) => t!(locale, "streambotstatus-connected", age = format_uptime(started, now)),
+        None => t!(locale, "streambotstatus-disconnected"),
+    };
+
+    // Only stream.online counts, each channel also has a stream.offline subscription
+    let subscriptions = list_subscriptions(&state.twitch_token, &state.client_id, &reqwest::Client::new()).await;
+    let subscriptions = match subscriptions {
+        Ok(subscriptions) => {
+            let session_id = state.session_id.read().clone();
+            let active: Vec<&str> = subscriptions
+                .iter()
+                .filter(|sub| sub["type"] == "stream.online" && sub["status"] == "enabled")
+                .filter(|sub| session_id.is_some() && sub["transport"]["session_id"].as_str() == session_id.as_deref())
+                .filter_map(|sub| sub["condition"]["broadcaster_user_id"].as_str())
+                .collect();
+            let active = if server.is_some() {
+                match get_users(&tracked, &[], &state.twitch_token, &state.client_id).await {
+                    Ok(users) => {
+                        let ids: HashSet<&str> = users.iter().map(|u| u.id.as_str()).collect();
+                        Some(active.iter().filter(|id| ids.contains(*id)).count())
+                    }
+                    Err(e) => {
+                        eprintln!("✗ Failed to look up Twitch users: {}", e);
+                        None
+                    }
+                }
+            } else {
+                Some(active.len())
+            };
+            match active {
+                Some(active) => t!(
+                    locale,
+                    "streambotstatus-subscriptions",
+                    active = active,
+                    tracked = tracked.len()
+                ),
+                None => t!(locale, "streambotstatus-subscriptions-unknown", tracked = tracked.len()),
+            }
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to list EventSub subscriptions: {}", e);
+            t!(locale, "streambotstatus-subscriptions-unknown", tracked = tracked.len())
+        }
+    };
+
+    let token = match validate_token(&state.twitch_token).await {
+        Ok(Some(0)) => t!(locale, "streambotstatus-token-valid-forever"),
+        Ok(Some(expires_in)) => t!(
+            locale,
+            "streambotstatus-token-valid",
+            expires = format_uptime(now, now + chrono::Duration::seconds(expires_in))
+        ),
+        Ok(None) => t!(locale, "streambotstatus-token-invalid"),
+        Err(e) => {
+            eprintln!("✗ Failed to validate Twitch token: {}", e);
+            t!(locale, "streambotstatus-token-unknown")
+        }
+    };
+
+    let title = match &server {
+        Some(server) => t!(locale, "streambotstatus-title-server", server = server.name.as_str()),
+        None => t!(locale, "streambotstatus-title-global"),
+    };
+    let lines = [
+        format!("**{}**", title),
+        t!(locale, "streambotstatus-eventsub", status = eventsub),
+        t!(
+            locale,
+            "streambotstatus-keepalive",
+            time = describe_since(status.last_keepalive, now, locale)
+        ),
+        subscriptions,
+        token,
+        t!(locale, "streambotstatus-outbox", pending = pending, failed = failed),
+        t!(
+            locale,
+            "streambotstatus-uptime",
+            uptime = format_uptime(state.started_at, now),
+            version = env!("CARGO_PKG_VERSION")
+        ),
+    ];
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(lines.join("\n"))
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,427 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{describe_role, find_role, find_text_channel};
+use crate::commands::permissions::is_server_admin;
+use crate::config::{DEFAULT_PREFIX, MAX_FLAP_COOLDOWN_MINUTES, MAX_PREFIX_LENGTH};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::QuietHours;
+use crate::notify::template::{MessageMode, Template};
+use crate::quota::{Quotas, Usage};
+use crate::settings::{load_server_settings, set_server_setting};
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "streamconfig",
+    required: &[],
+    optional: &["setting"],
+    rest: Some("value"),
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn streamconfig(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    // Templates keep their line breaks and spacing, so the value is the raw text
+    let value = args.rest().unwrap_or_default().to_string();
+
+    let channel = ctx.cache.get_channel(&ctx.message.channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // No arguments: show the current configuration
+    let Some(setting) = args.get("setting").map(str::to_lowercase) else {
+        let db = ctx.state.db.clone();
+        let server_id_clone = server_id.clone();
+        let author = ctx.message.author.clone();
+        let current_channel = ctx.message.channel.clone();
+        let (settings, usage) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+            let conn = db.get()?;
+            let settings = load_server_settings(&conn, &server_id_clone)?;
+            // Capacity is for the channel !addstream would use right now
+            let target = settings.default_channel.as_deref().unwrap_or(&current_channel);
+            let usage = Usage::load(&conn, &server_id_clone, &author, target)?;
+            Ok((settings, usage))
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+        let quotas = Quotas::for_server(&ctx.state.config, &settings);
+
+        let cooldown = match settings.flap_cooldown_minutes {
+            Some(minutes) => t!(locale, "streamconfig-minutes", minutes = minutes),
+            None => t!(
+                locale,
+                "streamconfig-minutes-default",
+                minutes = ctx.state.config.flap_cooldown_minutes
+            ),
+        };
+
+        let quiet_hours = match settings.quiet_hours() {
+            Some(quiet_hours) => quiet_hours.to_string(),
+            None => t!(locale, "value-off"),
+        };
+
+        let template = match &settings.default_template {
+            Some(template) => format!("`{}`", template),
+            None => t!(
+                locale,
+                "streamconfig-builtin-message",
+                message = format!("`{}`", Template::default_source(locale))
+            ),
+        };
+
+        let server_obj = ctx.cache.get_server(&server_id).unwrap();
+        let mention = match &settings.default_mention_role {
+            Some(role_id) => describe_role(&server_obj, role_id, locale),
+            None => t!(locale, "value-none"),
+        };
+
+        let manager_role = match &settings.manager_role {
+            Some(role_id) => describe_role(&server_obj, role_id, locale),
+            None => t!(locale, "value-none"),
+        };
+
+        let channel = match &settings.default_channel {
+            Some(channel_id) => format!("<#{}>", channel_id),
+            None => t!(locale, "streamconfig-channel-unset"),
+        };
+
+        let capacity = t!(
+            locale,
+            "streamconfig-capacity",
+            server_left = (quotas.per_server - usage.server).max(0),
+            server_max = quotas.per_server,
+            channel_left = (quotas.per_channel - usage.channel).max(0),
+            channel_max = quotas.per_channel,
+            target = settings
+                .default_channel
+                .clone()
+                .unwrap_or_else(|| ctx.message.channel.clone()),
+            user_left = (quotas.per_user - usage.user).max(0),
+            user_max = quotas.per_user
+        );
+
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(
+                locale,
+                "streamconfig-summary",
+                cooldown = cooldown,
+                quiet_hours = quiet_hours,
+                message = template,
+                mention = mention,
+                mode = settings.message_mode(None).as_str(),
+                channel = channel,
+                language = locale.code(),
+                prefix = settings.prefix(),
+                manager_role = manager_role,
+                capacity = capacity
+            ))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    let server_obj = ctx.cache.get_server(&server_id).unwrap();
+
+    match setting.as_str() {
+        "cooldown" => {
+            let minutes = match value.trim().parse::<i64>().ok() {
+                Some(m) if (0..=MAX_FLAP_COOLDOWN_MINUTES).contains(&m) => m,
+                _ => {
+                    ctx.message
+                        .reply(&ctx, true)
+                        .content(t!(
+                            locale,
+                            "streamconfig-cooldown-usage",
+                            max = MAX_FLAP_COOLDOWN_MINUTES
+                        ))
+                        .build()
+                        .await?;
+                    return Ok(());
+                }
+            };
+
+            let db = ctx.state.db.clone();
+            tokio::task::spawn_blocking(move || {
+                let conn = db.get()?;
+                set_server_setting(&conn, &server_id, "flap_cooldown_minutes", &minutes)
+            })
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+            let response = if minutes == 0 {
+                t!(locale, "streamconfig-cooldown-disabled")
+            } else {
+                t!(locale, "streamconfig-cooldown-set", minutes = minutes)
+            };
+
+            ctx.message
+                .reply(&ctx, true)
+                .content(response)
+                .build()
+                .await?;
+        }
+        "quiet" => {
+            let words: Vec<&str> = value.split_whitespace().collect();
+            let quiet_hours = match words.as_slice() {
+                ["off"] => None,
+                [start, end, timezone] | [start, end, timezone, _] => {
+                    let mode = words.get(3).copied().unwrap_or("silent");
+                    match QuietHours::parse(start, end, timezone, mode) {
+                        Ok(q) => Some(q),
+                        Err(e) => {
+                            ctx.message
+                                .reply(&ctx, true)
+                                .content(e.message(locale))
+                                .build()
+                                .await?;
+                            return Ok(());
+                        }
+                    }
+                }
+                _ => {
+                    ctx.message
+                        .reply(&ctx, true)
+                        .content(t!(locale, "streamconfig-quiet-usage"))
+                        .build()
+                        .await?;
+                    return Ok(());
+                }
+            };
+
+            let db = ctx.state.db.clone();
+            let quiet_hours_clone = quiet_hours.clone();
+            tokio::task::spawn_blocking(move || -> Result<(), Error> {
+                let conn = db.get()?;
+                let q = quiet_hours_clone.as_ref();
+                let start = q.map(|q| q.start.format("%H:%M").to_string());
+                let end = q.map(|q| q.end.format("%H:%M").to_string());
+                let timezone = q.map(|q| q.timezone.name().to_string());
+                let mode = q.map(|q| q.mode.as_str());
+                set_server_setting(&conn, &server_id, "quiet_start", &start)?;
+                set_server_setting(&conn, &server_id, "quiet_end", &end)?;
+                set_server_setting(&conn, &server_id, "quiet_timezone", &timezone)?;
+                set_server_setting(&conn, &server_id, "quiet_mode", &mode)
+            })
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+            let response = match quiet_hours {
+                Some(q) => t!(locale, "streamconfig-quiet-set", quiet_hours = q.to_string()),
+                None => t!(locale, "streamconfig-quiet-disabled"),
+            };
+
+            ctx.message
+                .reply(&ctx, true)
+                .content(response)
+                .build()
+                .await?;
+        }
+        "template" | "mention" | "mode" | "channel" | "language" | "managerrole" | "prefix"
+            if !value.trim().is_empty() =>
+        {
+            // Stream managers can't hand out their own role
+            if setting == "managerrole" && !is_server_admin(&ctx).await {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "streamconfig-managerrole-admin-only"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+
+            let trimmed = value.trim();
+            let reset = trimmed.eq_ignore_ascii_case("default")
+                || trimmed.eq_ignore_ascii_case("none")
+                || trimmed.eq_ignore_ascii_case("off");
+
+            let (column, new_value, response): (&'static str, Option<String>, String) =
+                match setting.as_str() {
+                    "template" if reset => (
+                        "default_template",
+                        None,
+                        t!(locale, "streamconfig-template-reset"),
+                    ),
+                    "template" => match Template::parse(&value) {
+                        Ok(_) => (
+                            "default_template",
+                            Some(value.clone()),
+                            t!(locale, "streamconfig-template-set", message = format!("`{}`", value)),
+                        ),
+                        Err(e) => {
+                            ctx.message
+                                .reply(&ctx, true)
+                                .content(t!(locale, "invalid-custom-message", error = e.message(locale)))
+                                .build()
+                                .await?;
+                            return Ok(());
+                        }
+                    },
+                    "mention" if reset => (
+                        "default_mention_role",
+                        None,
+                        t!(locale, "streamconfig-mention-reset"),
+                    ),
+                    "prefix" if reset => (
+                        "prefix",
+                        None,
+                        t!(locale, "streamconfig-prefix-set", prefix = DEFAULT_PREFIX),
+                    ),
+                    "prefix" if !value.contains(char::is_whitespace) && value.chars().count() <= MAX_PREFIX_LENGTH => (
+                        "prefix",
+                        Some(value.clone()),
+                        t!(locale, "streamconfig-prefix-set", prefix = value.as_str()),
+                    ),
+                    "prefix" => {
+                        ctx.message
+                            .reply(&ctx, true)
+                            .content(t!(locale, "streamconfig-prefix-usage", max = MAX_PREFIX_LENGTH))
+                            .build()
+                            .await?;
+                        return Ok(());
+                    }
+                    "managerrole" if reset => (
+                        "manager_role",
+                        None,
+                        t!(locale, "streamconfig-managerrole-reset"),
+                    ),
+                    "mention" | "managerrole" => match find_role(&server_obj, &value) {
+                        Some(role_id) => {
+                            let role = describe_role(&server_obj, &role_id, locale);
+                            if setting == "mention" {
+                                let response = t!(locale, "streamconfig-mention-set", role = role);
+                                ("default_mention_role", Some(role_id), response)
+                            } else {
+                                let response = t!(locale, "streamconfig-managerrole-set", role = role);
+                                ("manager_role", Some(role_id), response)
+                            }
+                        }
+                        None => {
+                            ctx.message
+                                .reply(&ctx, true)
+                                .content(t!(locale, "role-not-found", role = value.as_str()))
+                                .build()
+                                .await?;
+                            return Ok(());
+                        }
+                    },
+                    "mode" => match MessageMode::parse(&value) {
+                        Some(mode) => (
+                            "message_mode",
+                            Some(mode.as_str().to_string()),
+                            t!(locale, "streamconfig-mode-set", mode = mode.as_str()),
+                        ),
+                        None => {
+                            ctx.message
+                                .reply(&ctx, true)
+                                .content(t!(locale, "streamconfig-mode-usage"))
+                                .build()
+                                .await?;
+                            return Ok(());
+                        }
+                    },
+                    "language" => match Locale::parse(&value) {
+                        // Confirm in the language that was just chosen
+                        Some(new_locale) => (
+                            "language",
+                            Some(new_locale.code().to_string()),
+                            t!(new_locale, "streamconfig-language-set"),
+                        ),
+                        None => {
+                            ctx.message
+                                .reply(&ctx, true)
+                                .content(t!(
+                                    locale,
+                                    "streamconfig-language-usage",
+                                    languages = Locale::ALL
+                                        .iter()
+                                        .map(|l| l.code())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ))
+                                .build()
+                                .await?;
+                            return Ok(());
+                        }
+                    },
+                    _ if reset => (
+                        "default_channel",
+                        None,
+                        t!(locale, "streamconfig-channel-reset"),
+                    ),
+                    _ => {
+                        let channel_id = if value.eq_ignore_ascii_case("here") {
+                            Some(ctx.message.channel.clone())
+                        } else {
+                            find_text_channel(&ctx.cache, &server_id, &value)
+                        };
+                        match channel_id {
+                            Some(channel_id) => {
+                                let response = t!(
+                                    locale,
+                                    "streamconfig-channel-set",
+                                    channel = channel_id.as_str()
+                                );
+                                ("default_channel", Some(channel_id), response)
+                            }
+                            None => {
+                                ctx.message
+                                    .reply(&ctx, true)
+                                    .content(t!(
+                                        locale,
+                                        "text-channel-not-found",
+                                        channel = value.as_str()
+                                    ))
+                                    .build()
+                                    .await?;
+                                return Ok(());
+                            }
+                        }
+                    }
+                };
+
+            let db = ctx.state.db.clone();
+            let server_id_clone = server_id.clone();
+            let new_value_clone = new_value.clone();
+            tokio::task::spawn_blocking(move || {
+                let conn = db.get()?;
+                set_server_setting(&conn, &server_id_clone, column, &new_value_clone)
+            })
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+            if column == "prefix" {
+                let prefix = new_value.unwrap_or_else(|| DEFAULT_PREFIX.to_string());
+                ctx.state.prefixes.write().insert(server_id, prefix);
+            }
+
+            ctx.message
+                .reply(&ctx, true)
+                .content(response)
+                .build()
+                .await?;
+        }
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "streamconfig-usage"))
+                .build()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,375 @@
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use rusqlite::{Connection, params};
+use serde::{Deserialize, Serialize};
+
+/// File formats `!exportstreams` writes and `!importstreams` reads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileFormat {
+    Json,
+    Csv,
+}
+
+impl FileFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "json" => Some(FileFormat::Json),
+            "csv" => Some(FileFormat::Csv),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format of an uploaded file, by extension first, then by content.
+    pub fn detect(filename: &str, content: &str) -> Self {
+        let filename = filename.to_lowercase();
+        if filename.ends_with(".csv") {
+            FileFormat::Csv
+        } else if filename.ends_with(".json") || content.trim_start().starts_with(['[', '{']) {
+            FileFormat::Json
+        } else {
+            FileFormat::Csv
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Json => "json",
+            FileFormat::Csv => "csv",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FileFormat::Json => "application/json",
+            FileFormat::Csv => "text/csv",
+        }
+    }
+}
+
+/// One subscription as written to an export file.
+///
+/// Target channel and mention role are stored by ID with their names next to
+/// them, so a file from another server can still be matched up by name.
+/// A mention role of `none` turns off the server's default mention.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreamRow {
+    pub channel: String,
+    pub target_channel: String,
+    pub target_channel_name: String,
+    pub owner: String,
+    pub message: Option<String>,
+    pub games: Option<String>,
+    pub keywords: Option<String>,
+    pub mention_role: Option<String>,
+    pub mention_role_name: Option<String>,
+    pub mode: Option<String>,
+    pub enabled: bool,
+}
+
+impl Default for StreamRow {
+    fn default() -> Self {
+        Self {
+            channel: String::new(),
+            target_channel: String::new(),
+            target_channel_name: String::new(),
+            owner: String::new(),
+            message: None,
+            games: None,
+            keywords: None,
+            mention_role: None,
+            mention_role_name: None,
+            mode: None,
+            enabled: true,
+        }
+    }
+}
+
+/// A subscription as stored in the `streams` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredStream {
+    /// Row ID, unset for streams read from a file
+    pub id: Option<i64>,
+    pub channel_name: String,
+    pub added_in_channel: String,
+    pub user_id: String,
+    pub custom_message: Option<String>,
+    pub game_filter: Option<String>,
+    pub title_filter: Option<String>,
+    pub mention_role: Option<String>,
+    pub message_mode: Option<String>,
+    /// Not paused with `!editstream`
+    pub enabled: bool,
+}
+
+/// Every subscription in a server, ordered by channel name. Streams added by
+/// a followed team are left out, the team sync owns those. Older data can
+/// hold the same stream and target twice under different owners; only the
+/// oldest of those rows is returned.
+pub fn load_server_streams(conn: &Connection, server_id: &str) -> Result<Vec<StoredStream>, Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, channel_name, added_in_channel, user_id, custom_message, game_filter, title_filter, mention_role, message_mode, paused = 0
+         FROM streams WHERE server_id = ?1 AND team_id IS NULL ORDER BY channel_name, added_in_channel, id",
+    )?;
+    let mut streams = stmt
+        .query_map(params![server_id], |row| {
+            Ok(StoredStream {
+                id: row.get(0)?,
+                channel_name: row.get(1)?,
+                added_in_channel: row.get(2)?,
+                user_id: row.get(3)?,
+                custom_message: row.get(4)?,
+                game_filter: row.get(5)?,
+                title_filter: row.get(6)?,
+                mention_role: row.get(7)?,
+                message_mode: row.get(8)?,
+                enabled: row.get(9)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    streams.dedup_by(|later, first| {
+        later.channel_name == first.channel_name && later.added_in_channel == first.added_in_channel
+    });
+    Ok(streams)
+}
+
+const COLUMNS: [&str; 11] = [
+    "channel",
+    "target_channel",
+    "target_channel_name",
+    "owner",
+    "message",
+    "games",
+    "keywords",
+    "mention_role",
+    "mention_role_name",
+    "mode",
+    "enabled",
+];
+
+pub fn write(rows: &[StreamRow], format: FileFormat) -> String {
+    match format {
+        FileFormat::Json => serde_json::to_string_pretty(rows).unwrap_or_default(),
+        FileFormat::Csv => write_csv(rows),
+    }
+}
+
+/// Why an uploaded file couldn't be read, with the line that failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileError {
+    InvalidJson { line: usize, column: usize },
+    UnclosedQuote { line: usize },
+    MissingChannelColumn,
+    InvalidEnabled { line: usize, value: String },
+}
+
+impl FileError {
+    /// The error as shown to users in a command reply.
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            FileError::InvalidJson { line, column } => {
+                t!(locale, "streamfile-invalid-json", line = *line, column = *column)
+            }
+            FileError::UnclosedQuote { line } => t!(locale, "streamfile-unclosed-quote", line = *line),
+            FileError::MissingChannelColumn => t!(locale, "streamfile-missing-channel"),
+            FileError::InvalidEnabled { line, value } => t!(
+                locale,
+                "streamfile-invalid-enabled",
+                line = *line,
+                value = value.as_str()
+            ),
+        }
+    }
+}
+
+/// Reads an export file. Errors name the line or JSON position that failed.
+pub fn read(content: &str, format: FileFormat) -> Result<Vec<StreamRow>, FileError> {
+    match format {
+        FileFormat::Json => serde_json::from_str(content).map_err(|e| FileError::InvalidJson {
+            line: e.line(),
+            column: e.column(),
+        }),
+        FileFormat::Csv => read_csv(content),
+    }
+}
+
+fn write_csv(rows: &[StreamRow]) -> String {
+    let mut out = COLUMNS.join(",");
+    out.push_str("\r\n");
+    for row in rows {
+        let fields = [
+            row.channel.as_str(),
+            row.target_channel.as_str(),
+            row.target_channel_name.as_str(),
+            row.owner.as_str(),
+            row.message.as_deref().unwrap_or_default(),
+            row.games.as_deref().unwrap_or_default(),
+            row.keywords.as_deref().unwrap_or_default(),
+            row.mention_role.as_deref().unwrap_or_default(),
+            row.mention_role_name.as_deref().unwrap_or_default(),
+            row.mode.as_deref().unwrap_or_default(),
+            if row.enabled { "true" } else { "false" },
+        ];
+        let escaped: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+        out.push_str(&escaped.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits CSV text into records. Quoted fields may contain commas, doubled
+/// quotes and line breaks.
+fn parse_csv(content: &str) -> Result<Vec<(usize, Vec<String>)>, FileError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            _ => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err(FileError::UnclosedQuote { line: record_line });
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push((record_line, record));
+    }
+    Ok(records)
+}
+
+fn read_csv(content: &str) -> Result<Vec<StreamRow>, FileError> {
+    let mut records = parse_csv(content)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let header: Vec<String> = header.iter().map(|h| h.trim().to_lowercase()).collect();
+    if !header.iter().any(|h| h == "channel") {
+        return Err(FileError::MissingChannelColumn);
+    }
+
+    let mut rows = Vec::new();
+    for (line, record) in records {
+        let mut row = StreamRow::default();
+        for (name, value) in header.iter().zip(record) {
+            let text = Some(value.clone()).filter(|v| !v.is_empty());
+            match name.as_str() {
+                "channel" => row.channel = value,
+                "target_channel" => row.target_channel = value,
+                "target_channel_name" => row.target_channel_name = value,
+                "owner" => row.owner = value,
+                "message" => row.message = text,
+                "games" => row.games = text,
+                "keywords" => row.keywords = text,
+                "mention_role" => row.mention_role = text,
+                "mention_role_name" => row.mention_role_name = text,
+                "mode" => row.mode = text,
+                "enabled" => {
+                    row.enabled = match value.trim().to_lowercase().as_str() {
+                        "" | "true" | "on" | "yes" | "1" => true,
+                        "false" | "off" | "no" | "0" => false,
+                        other => {
+                            return Err(FileError::InvalidEnabled { line, value: other.to_string() });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(channel: &str, message: Option<&str>, enabled: bool) -> StreamRow {
+        StreamRow {
+            channel: channel.to_string(),
+            target_channel: "01TARGET".to_string(),
+            target_channel_name: "live".to_string(),
+            owner: "01OWNER".to_string(),
+            message: message.map(str::to_string),
+            enabled,
+            ..StreamRow::default()
+        }
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let rows = vec![
+            row("plain", None, true),
+            row("tricky", Some("a, \"quoted\"\nsecond line"), false),
+        ];
+        assert_eq!(read(&write(&rows, FileFormat::Csv), FileFormat::Csv), Ok(rows));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let rows = vec![row("plain", Some("hi"), false)];
+        assert_eq!(read(&write(&rows, FileFormat::Json), FileFormat::Json), Ok(rows));
+    }
+
+    #[test]
+    fn csv_columns_may_be_missing_or_reordered() {
+        let rows = read("\u{feff}Enabled,Channel\r\noff,someone\r\n\r\n,other\r\n", FileFormat::Csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].channel.as_str(), rows[0].enabled), ("someone", false));
+        assert_eq!((rows[1].channel.as_str(), rows[1].enabled), ("other", true));
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(
+            read("channel,message\na,\"open\nb,c\n", FileFormat::Csv),
+            Err(FileError::UnclosedQuote { line: 2 })
+        );
+        assert_eq!(read("name\na\n", FileFormat::Csv), Err(FileError::MissingChannelColumn));
+        assert_eq!(
+            read("channel,enabled\na,true\nb,maybe\n", FileFormat::Csv),
+            Err(FileError::InvalidEnabled { line: 3, value: "maybe".to_string() })
+        );
+        assert!(matches!(
+            read("[\n  {\"channel\": 5}\n]", FileFormat::Json),
+            Err(FileError::InvalidJson { line: 2, .. })
+        ));
+    }
+}
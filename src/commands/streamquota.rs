@@ -0,0 +1,158 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::quota::{Quotas, server_usage};
+use crate::settings::{load_server_settings, set_server_setting};
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "streamquota",
+    required: &["server"],
+    optional: &["limit", "value"],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+/// Shows a limit, marking it when it is the global default rather than an override.
+fn describe_limit(limit: i64, overridden: bool, locale: Locale) -> String {
+    if overridden {
+        limit.to_string()
+    } else {
+        t!(locale, "streamquota-default", max = limit)
+    }
+}
+
+pub async fn streamquota(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+
+    if ctx.state.config.operator_id.as_deref() != Some(ctx.message.author.as_str()) {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "streamquota-operator-only"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+    let server_arg = args.get("server").unwrap_or_default();
+    let change = match (args.get("limit"), args.get("value")) {
+        (None, None) => None,
+        (Some(limit), Some(value)) => Some((limit, value)),
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "streamquota-usage"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // The operator may not be a member of the server, so it is named by ID
+    let server_id = if server_arg.eq_ignore_ascii_case("here") {
+        match ctx.cache.get_channel(&ctx.message.channel) {
+            Some(stoat::types::Channel::TextChannel { server, .. }) => server,
+            _ => {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "error-server-only"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        }
+    } else {
+        server_arg.to_string()
+    };
+
+    let Some(server) = ctx.cache.get_server(&server_id) else {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "streamquota-unknown-server", server = server_id.as_str()))
+            .build()
+            .await?;
+        return Ok(());
+    };
+
+    if let Some((limit, value)) = change {
+        let column = match limit {
+            "user" => "max_streams_per_user",
+            "server" => "max_streams_per_server",
+            "channel" => "max_streams_per_channel",
+            _ => {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "streamquota-usage"))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        };
+        let limit = if value.eq_ignore_ascii_case("default") {
+            None
+        } else {
+            match value.parse::<i64>() {
+                Ok(limit) if limit >= 0 => Some(limit),
+                _ => {
+                    ctx.message
+                        .reply(&ctx, true)
+                        .content(t!(locale, "streamquota-usage"))
+                        .build()
+                        .await?;
+                    return Ok(());
+                }
+            }
+        };
+
+        let db = ctx.state.db.clone();
+        let server_id_clone = server_id.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn = db.get()?;
+            set_server_setting(&conn, &server_id_clone, column, &limit)
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+        println!("✓ Set {} for server {} to {:?}", column, server_id, limit);
+    }
+
+    let db = ctx.state.db.clone();
+    let server_id_clone = server_id.clone();
+    let (settings, used) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let used = server_usage(&conn, &server_id_clone)?;
+        Ok((load_server_settings(&conn, &server_id_clone)?, used))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+    let quotas = Quotas::for_server(&ctx.state.config, &settings);
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(t!(
+            locale,
+            "streamquota-summary",
+            server = server.name.as_str(),
+            used = used,
+            user = describe_limit(quotas.per_user, settings.max_streams_per_user.is_some(), locale),
+            server_max = describe_limit(
+                quotas.per_server,
+                settings.max_streams_per_server.is_some(),
+                locale
+            ),
+            channel = describe_limit(
+                quotas.per_channel,
+                settings.max_streams_per_channel.is_some(),
+                locale
+            )
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
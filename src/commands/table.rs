@@ -0,0 +1,112 @@
+use crate::i18n::{Locale, t};
+
+/// Renders rows as a fixed-width table inside a code block so the columns
+/// line up in chat.
+pub fn code_table(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+
+    let mut table = String::from("```\n");
+    table.push_str(&format_row(header));
+    table.push('\n');
+    for row in rows {
+        table.push_str(&format_row(row));
+        table.push('\n');
+    }
+    table.push_str("```");
+    table
+}
+
+/// Renders as many rows as fit in `max_length` characters, then a line
+/// saying how many were left out, so long batches stay within one message.
+pub fn code_table_within(header: &[String], rows: &[Vec<String>], max_length: usize, locale: Locale) -> String {
+    // A table only gets longer with more rows, so the cut-off can be searched for
+    let fits = |shown: usize| code_table(header, &rows[..shown]).chars().count() <= max_length;
+    let (mut shown, mut most) = (0, rows.len());
+    while shown < most {
+        let middle = (shown + most).div_ceil(2);
+        if fits(middle) {
+            shown = middle;
+        } else {
+            most = middle - 1;
+        }
+    }
+    let mut table = code_table(header, &rows[..shown]);
+    if shown < rows.len() {
+        table.push('\n');
+        table.push_str(&t!(locale, "bulk-more", count = rows.len() - shown));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn pads_columns_to_the_widest_cell() {
+        let table = code_table(
+            &strings(&["Channel", "Result"]),
+            &[strings(&["a", "added"]), strings(&["longer_name", "not found"])],
+        );
+        assert_eq!(
+            table,
+            "```\nChannel      Result\na            added\nlonger_name  not found\n```"
+        );
+    }
+
+    #[test]
+    fn widths_count_characters_not_bytes() {
+        let table = code_table(&strings(&["ä", "b"]), &[strings(&["äää", "x"])]);
+        assert_eq!(table, "```\nä    b\näää  x\n```");
+    }
+
+    #[test]
+    fn keeps_every_row_that_fits() {
+        let header = strings(&["Channel", "Result"]);
+        let rows = vec![strings(&["a", "added"]), strings(&["b", "added"])];
+        assert_eq!(
+            code_table_within(&header, &rows, 2000, Locale::En),
+            code_table(&header, &rows)
+        );
+    }
+
+    #[test]
+    fn leaves_out_rows_that_do_not_fit() {
+        let header = strings(&["Channel", "Result"]);
+        let rows: Vec<Vec<String>> = (0..200).map(|i| strings(&[&format!("channel{}", i), "added"])).collect();
+        let table = code_table_within(&header, &rows, 500, Locale::En);
+        let (body, more) = table.rsplit_once('\n').unwrap();
+        assert!(body.chars().count() <= 500);
+        assert!(body.ends_with("```"));
+        let shown = body.lines().count() - 3;
+        assert_eq!(more, format!("… and {} more", 200 - shown));
+    }
+
+    #[test]
+    fn one_oversized_row_leaves_only_the_header() {
+        let header = strings(&["Channel", "Result"]);
+        let rows = vec![strings(&[&"x".repeat(600), "not found"])];
+        assert_eq!(
+            code_table_within(&header, &rows, 500, Locale::En),
+            "```\nChannel  Result\n```\n… and 1 more"
+        );
+    }
+}
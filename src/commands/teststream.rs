@@ -0,0 +1,254 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::twitch_login;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::notify::quiet_hours::strip_role_mentions;
+use crate::notify::template::{MessageMode, Template, TemplateContext};
+use crate::notify::{FailureKind, classify_error, send_message};
+use crate::settings::load_server_settings;
+use crate::twitch::streams::get_stream;
+use crate::twitch::validate_twitch_channel;
+use rusqlite::{OptionalExtension, params};
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "teststream",
+    required: &["channel"],
+    optional: &["send"],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn teststream(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let send = match args.get("send") {
+        None => false,
+        Some(arg) if arg.eq_ignore_ascii_case("send") => true,
+        Some(_) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "teststream-usage"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
+    };
+    let channel = ctx.cache.get_channel(&ctx.message.channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    // Prefer the subscription in this channel, otherwise any one in the server
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.clone();
+    let server_id_clone = server_id.clone();
+    let current_channel = ctx.message.channel.clone();
+    let (subscription, settings) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let subscription = conn
+            .query_row(
+                "SELECT id, added_in_channel, custom_message, mention_role, message_mode, enabled FROM streams
+                 WHERE channel_name = ?1 AND server_id = ?2
+                 ORDER BY added_in_channel = ?3 DESC, id LIMIT 1",
+                params![channel_name_clone, server_id_clone, current_channel],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                        row.get::<_, Option<String>>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                        row.get::<_, bool>(5)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let settings = load_server_settings(&conn, &server_id_clone)?;
+        Ok((subscription, settings))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let (stream_id, target_channel, custom_message, mention_role, message_mode, enabled) =
+        match subscription {
+            Some(s) => s,
+            None => {
+                ctx.message
+                    .reply(&ctx, true)
+                    .content(t!(locale, "stream-not-in-server", channel = channel_name.as_str()))
+                    .build()
+                    .await?;
+                return Ok(());
+            }
+        };
+
+    let broadcaster_id = match validate_twitch_channel(
+        &channel_name,
+        &ctx.state.twitch_token,
+        &ctx.state.client_id,
+    )
+    .await
+    {
+        Ok(Some(id)) => id,
+        Ok(None) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "twitch-channel-not-found", channel = channel_name.as_str()))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to validate Twitch channel: {}", e);
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "twitch-lookup-failed"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let stream = match get_stream(&broadcaster_id, &ctx.state.twitch_token, &ctx.state.client_id).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("✗ Failed to fetch stream details for {}: {}", channel_name, e);
+            None
+        }
+    };
+    let (mut template_context, source) = match stream.as_ref() {
+        Some(stream) => (
+            TemplateContext::new(&stream.user_login, &stream.user_name, Some(stream)),
+            t!(locale, "teststream-source-live"),
+        ),
+        None => (
+            TemplateContext::sample(&channel_name, locale),
+            t!(locale, "teststream-source-sample"),
+        ),
+    };
+    template_context.mention_role = settings.mention_role(mention_role.as_deref());
+
+    let template = match settings
+        .template_source(custom_message.as_deref())
+        .map(Template::parse)
+    {
+        Some(Ok(template)) => template,
+        Some(Err(e)) => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "teststream-invalid-message", error = e.message(locale)))
+                .build()
+                .await?;
+            return Ok(());
+        }
+        None => Template::default_template(locale),
+    };
+    let message = template.render(&template_context);
+    let embed = match settings.message_mode(message_mode.as_deref()) {
+        MessageMode::Embed => Some(template_context.embed(locale)),
+        MessageMode::Text => None,
+    };
+
+    if !send {
+        let mut response = format!(
+            "**{}** ({}):\n\n{}",
+            t!(
+                locale,
+                "teststream-preview-title",
+                channel = channel_name.as_str(),
+                target = target_channel.as_str()
+            ),
+            source,
+            // Don't ping the role from a preview
+            strip_role_mentions(&message)
+        );
+        if let Some(quiet_hours) = settings.quiet_hours()
+            && quiet_hours.is_active(chrono::Utc::now())
+        {
+            response.push_str("\n\n");
+            response.push_str(&t!(
+                locale,
+                "teststream-quiet-active",
+                quiet_hours = quiet_hours.to_string()
+            ));
+        }
+        response.push_str("\n\n");
+        response.push_str(&t!(
+            locale,
+            "teststream-send-hint",
+            channel = channel_name.as_str(),
+            target = target_channel.as_str()
+        ));
+
+        let mut reply = ctx.message.reply(&ctx, true);
+        reply.content(response);
+        if let Some(embed) = embed {
+            reply.embed(embed);
+        }
+        reply.build().await?;
+        return Ok(());
+    }
+
+    let key = format!("test:{}", ctx.message.id);
+    let response = match send_message(&ctx.http, &target_channel, &message, embed.as_ref(), &key).await {
+        Ok(_) => {
+            if !enabled {
+                // The bot can post again, so live notifications can resume
+                let db = ctx.state.db.clone();
+                tokio::task::spawn_blocking(move || -> Result<(), Error> {
+                    let conn = db.get()?;
+                    conn.execute("UPDATE streams SET enabled = 1 WHERE id = ?1", params![stream_id])?;
+                    Ok(())
+                })
+                .await
+                .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+                t!(
+                    locale,
+                    "teststream-sent-reenabled",
+                    target = target_channel.as_str(),
+                    channel = channel_name.as_str()
+                )
+            } else {
+                t!(locale, "teststream-sent", target = target_channel.as_str())
+            }
+        }
+        Err(e) => {
+            eprintln!("✗ Test send to {} failed: {:?}", target_channel, e);
+            match classify_error(&e) {
+                FailureKind::BrokenTarget => {
+                    t!(locale, "teststream-cannot-post", target = target_channel.as_str())
+                }
+                FailureKind::RateLimited { .. } => t!(locale, "teststream-rate-limited"),
+                _ => t!(locale, "teststream-send-failed"),
+            }
+        }
+    };
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
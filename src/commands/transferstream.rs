@@ -0,0 +1,156 @@
+use crate::commands::{CmdCtx, server_locale};
+use crate::commands::args::{Spec, parse_args};
+use crate::commands::lookup::{find_subscription, reply_not_found, twitch_login, user_id};
+use crate::commands::permissions::can_manage_all_streams;
+use crate::error::Error;
+use crate::i18n::t;
+use crate::quota::{Quotas, Usage};
+use crate::settings::load_server_settings;
+use rusqlite::params;
+use stoat::MessageExt;
+
+const SPEC: Spec = Spec {
+    name: "transferstream",
+    required: &["channel", "@user"],
+    optional: &[],
+    rest: None,
+    rest_required: false,
+    options: &[],
+};
+
+pub async fn transferstream(ctx: CmdCtx) -> Result<(), Error> {
+    let locale = server_locale(&ctx).await?;
+    let Some(args) = parse_args(&ctx, &SPEC, locale).await? else {
+        return Ok(());
+    };
+
+    let channel_arg = args.get("channel").unwrap_or_default();
+    let Some(channel_name) = twitch_login(&ctx, channel_arg, locale).await? else {
+        return Ok(());
+    };
+    let user_arg = args.get("@user").unwrap_or_default();
+    let new_owner = user_id(user_arg).to_string();
+    let current_channel = ctx.message.channel.clone();
+
+    let channel = ctx.cache.get_channel(&current_channel).unwrap();
+    let server_id = match channel {
+        stoat::types::Channel::TextChannel { server, .. } => server,
+        _ => {
+            ctx.message
+                .reply(&ctx, true)
+                .content(t!(locale, "error-server-only"))
+                .build()
+                .await?;
+            return Ok(());
+        }
+    };
+
+    if ctx.http.fetch_member(&server_id, &new_owner).await.is_err() {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "transferstream-user-not-found", user = user_arg))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let channel_name_clone = channel_name.clone();
+    let new_owner_clone = new_owner.clone();
+    let server_id_clone = server_id.clone();
+    let (subscription, usage, settings) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        let subscription = find_subscription(&conn, &channel_name_clone, &server_id_clone, &current_channel)?;
+        let usage = Usage::load(&conn, &server_id_clone, &new_owner_clone, &current_channel)?;
+        let settings = load_server_settings(&conn, &server_id_clone)?;
+        Ok((subscription, usage, settings))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let (stream_id, owner) = match subscription {
+        Some(s) => s,
+        None => {
+            let not_found = t!(locale, "stream-not-in-server", channel = channel_name.as_str());
+            reply_not_found(&ctx, &channel_name, &server_id, not_found, locale).await?;
+            return Ok(());
+        }
+    };
+
+    let author = ctx.message.author.clone();
+    if owner != author && !can_manage_all_streams(&ctx).await {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(locale, "transferstream-not-yours"))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    if owner == new_owner {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(
+                locale,
+                "transferstream-already-owner",
+                channel = channel_name.as_str(),
+                user = new_owner.as_str()
+            ))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    // The stream counts against the new owner's limit from now on. Server and
+    // channel totals don't change.
+    let max_streams = Quotas::for_server(&ctx.state.config, &settings).per_user;
+    if usage.user >= max_streams {
+        ctx.message
+            .reply(&ctx, true)
+            .content(t!(
+                locale,
+                "transferstream-limit-reached",
+                user = new_owner.as_str(),
+                max = max_streams
+            ))
+            .build()
+            .await?;
+        return Ok(());
+    }
+
+    let db = ctx.state.db.clone();
+    let new_owner_clone = new_owner.clone();
+    let update_result = tokio::task::spawn_blocking(move || -> Result<usize, Error> {
+        let conn = db.get()?;
+        Ok(conn.execute(
+            "UPDATE streams SET user_id = ?1 WHERE id = ?2",
+            params![new_owner_clone, stream_id],
+        )?)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?;
+
+    let response = match update_result {
+        Ok(_) => {
+            println!("✓ Transferred stream {} from {} to {}", channel_name, owner, new_owner);
+            t!(
+                locale,
+                "transferstream-transferred",
+                channel = channel_name.as_str(),
+                user = new_owner.as_str()
+            )
+        }
+        Err(e) => {
+            eprintln!("✗ Error transferring stream: {}", e);
+            t!(locale, "transferstream-failed")
+        }
+    };
+
+    ctx.message
+        .reply(&ctx, true)
+        .content(response)
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -4,10 +4,48 @@ pub const RATE_LIMIT_DELAY_MS: u64 = 100;
 pub const RECONNECT_DELAY_SECS: u64 = 5;
 pub const MAX_API_RETRIES: u32 = 3;
 pub const RETRY_BASE_DELAY_MS: u64 = 100;
+pub const MAX_FLAP_COOLDOWN_MINUTES: i64 = 120;
+pub const OUTBOX_POLL_SECS: u64 = 5;
+pub const OUTBOX_MAX_ATTEMPTS: u32 = 8;
+pub const OUTBOX_RETRY_BASE_SECS: i64 = 2;
+pub const OUTBOX_MAX_BACKOFF_SECS: i64 = 600;
+pub const OUTBOX_RETENTION_DAYS: i64 = 7;
+pub const MAX_CONCURRENT_SENDS: usize = 4;
+pub const MAX_CONCURRENT_SUBSCRIBES: usize = 4;
+pub const HELIX_USERS_PER_REQUEST: usize = 100;
+pub const HELIX_STREAMS_PER_REQUEST: usize = 100;
+pub const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+pub const DEFAULT_STOAT_API_URL: &str = "https://api.stoat.chat";
+pub const DEFAULT_PREFIX: &str = "!";
+pub const MAX_PREFIX_LENGTH: usize = 8;
+pub const MAX_IMPORT_FILE_BYTES: usize = 1024 * 1024;
+pub const IMPORT_CONFIRM_TIMEOUT_SECS: u64 = 60;
+pub const MAX_IMPORT_LINES_SHOWN: usize = 15;
+/// Room for one page of a listing, below Stoat's 2000 character message limit
+pub const LIST_PAGE_LENGTH: usize = 1700;
+pub const TEAM_SYNC_INTERVAL_SECS: u64 = 60 * 60;
+pub const CATEGORY_POLL_SECS: u64 = 120;
+/// Helix pages of 100 streams read per category and poll, most viewed first
+pub const CATEGORY_POLL_MAX_PAGES: usize = 3;
+/// How long a stream ID stays known after it was last seen above the threshold
+pub const CATEGORY_SEEN_RETENTION_HOURS: i64 = 24;
 
 #[derive(Debug, Clone)]
 pub struct Config {
+    /// Streams one member may add in a single server
     pub max_streams_per_user: i64,
+    pub max_streams_per_server: i64,
+    pub max_streams_per_channel: i64,
+    /// Twitch categories one server may follow
+    pub max_categories_per_server: i64,
+    /// New category streams posted per server and poll, the rest are only counted
+    pub max_category_streams_per_poll: usize,
+    /// Stoat user ID allowed to override quotas for any server
+    pub operator_id: Option<String>,
+    pub flap_cooldown_minutes: i64,
+    pub stoat_api_url: String,
+    /// Overrides the WebSocket URL advertised by the API root
+    pub stoat_ws_url: Option<String>,
 }
 
 impl Default for Config {
@@ -17,6 +55,35 @@ impl Default for Config {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(3),
+            max_streams_per_server: env::var("MAX_STREAMS_PER_SERVER")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(25),
+            max_streams_per_channel: env::var("MAX_STREAMS_PER_CHANNEL")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(10),
+            max_categories_per_server: env::var("MAX_CATEGORIES_PER_SERVER")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3),
+            max_category_streams_per_poll: env::var("MAX_CATEGORY_STREAMS_PER_POLL")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(5),
+            operator_id: env::var("BOT_OPERATOR_ID").ok().filter(|s| !s.is_empty()),
+            flap_cooldown_minutes: env::var("FLAP_COOLDOWN_MINUTES")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0)
+                .clamp(0, MAX_FLAP_COOLDOWN_MINUTES),
+            stoat_api_url: env::var("STOAT_API_URL")
+                .ok()
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| DEFAULT_STOAT_API_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            stoat_ws_url: env::var("STOAT_WS_URL").ok().filter(|s| !s.is_empty()),
         }
     }
 }
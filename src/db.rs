@@ -1,6 +1,7 @@
 use crate::error::Error;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::Connection;
 
 pub fn init_database() -> Result<Pool<SqliteConnectionManager>, Error> {
     let manager = SqliteConnectionManager::file("streams.db");
@@ -20,6 +21,114 @@ pub fn init_database() -> Result<Pool<SqliteConnectionManager>, Error> {
         [],
     )?;
 
+    add_column_if_missing(&conn, "streams", "server_id", "TEXT")?;
+    add_column_if_missing(&conn, "streams", "enabled", "INTEGER NOT NULL DEFAULT 1")?;
+    // Paused by a manager with !editstream, unlike `enabled` which the bot
+    // clears when it can't post in the target channel
+    add_column_if_missing(&conn, "streams", "paused", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(&conn, "streams", "mention_role", "TEXT")?;
+    add_column_if_missing(&conn, "streams", "game_filter", "TEXT")?;
+    add_column_if_missing(&conn, "streams", "title_filter", "TEXT")?;
+    add_column_if_missing(&conn, "streams", "message_mode", "TEXT")?;
+    // Set on streams added for a Twitch team, which follow its membership
+    add_column_if_missing(&conn, "streams", "team_id", "INTEGER")?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS teams (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            team_name TEXT NOT NULL,
+            display_name TEXT NOT NULL,
+            added_in_channel TEXT NOT NULL,
+            user_id TEXT NOT NULL,
+            members TEXT NOT NULL DEFAULT '',
+            date TEXT NOT NULL,
+            UNIQUE(server_id, team_name)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS categories (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            game_id TEXT NOT NULL,
+            game_name TEXT NOT NULL,
+            added_in_channel TEXT NOT NULL,
+            user_id TEXT NOT NULL,
+            min_viewers INTEGER NOT NULL DEFAULT 0,
+            language TEXT,
+            primed INTEGER NOT NULL DEFAULT 0,
+            date TEXT NOT NULL,
+            UNIQUE(server_id, game_id)
+        )",
+        [],
+    )?;
+
+    // Category streams already announced, or found when the category was added
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS category_seen (
+            category_id INTEGER NOT NULL,
+            stream_id TEXT NOT NULL,
+            seen_at TEXT NOT NULL,
+            PRIMARY KEY(category_id, stream_id)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS server_settings (
+            server_id TEXT PRIMARY KEY,
+            flap_cooldown_minutes INTEGER
+        )",
+        [],
+    )?;
+
+    add_column_if_missing(&conn, "server_settings", "quiet_start", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "quiet_end", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "quiet_timezone", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "quiet_mode", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "default_template", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "default_mention_role", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "message_mode", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "default_channel", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "language", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "manager_role", "TEXT")?;
+    add_column_if_missing(&conn, "server_settings", "max_streams_per_user", "INTEGER")?;
+    add_column_if_missing(&conn, "server_settings", "max_streams_per_server", "INTEGER")?;
+    add_column_if_missing(&conn, "server_settings", "max_streams_per_channel", "INTEGER")?;
+    add_column_if_missing(&conn, "server_settings", "prefix", "TEXT")?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS quiet_queue (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            channel_id TEXT NOT NULL,
+            channel_name TEXT NOT NULL,
+            display_name TEXT NOT NULL,
+            went_live_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS outbox (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            idempotency_key TEXT NOT NULL UNIQUE,
+            channel_id TEXT NOT NULL,
+            content TEXT NOT NULL,
+            state TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            next_attempt_at INTEGER NOT NULL,
+            last_error TEXT,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    add_column_if_missing(&conn, "outbox", "message_id", "TEXT")?;
+    add_column_if_missing(&conn, "outbox", "embed", "TEXT")?;
+
     conn.execute(
         "CREATE INDEX IF NOT EXISTS idx_channel_name ON streams(channel_name)",
         [],
@@ -32,6 +141,41 @@ pub fn init_database() -> Result<Pool<SqliteConnectionManager>, Error> {
         "CREATE INDEX IF NOT EXISTS idx_added_in_channel ON streams(added_in_channel)",
         [],
     )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_server_id ON streams(server_id)",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_team_id ON streams(team_id)",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_outbox_state ON outbox(state, next_attempt_at)",
+        [],
+    )?;
 
     Ok(pool)
 }
+
+// Columns added after the first release; older databases are upgraded in place.
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), Error> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )?;
+    }
+
+    Ok(())
+}
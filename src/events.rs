@@ -1,9 +1,13 @@
 use crate::{commands::CommandHandler, error::Error};
-use stoat::{Context, EventHandler, async_trait, types::Message};
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::params;
+use stoat::{Context, EventHandler, async_trait, types::Channel, types::Message};
 
 #[derive(Clone)]
 pub struct Events {
     pub command_handler: CommandHandler,
+    pub db: Pool<SqliteConnectionManager>,
 }
 
 #[async_trait]
@@ -17,6 +21,8 @@ impl EventHandler for Events {
             .map(|u| u.username.clone())
             .unwrap_or_else(|| "Unknown".to_string());
         println!("✓ Logged into {}", username);
+
+        backfill_server_ids(&context, self.db.clone()).await?;
         Ok(())
     }
 
@@ -26,3 +32,47 @@ impl EventHandler for Events {
             .await
     }
 }
+
+// Streams added before server settings existed have no server_id; resolve them
+// from the channel cache once it is populated.
+async fn backfill_server_ids(
+    context: &Context,
+    db: Pool<SqliteConnectionManager>,
+) -> Result<(), Error> {
+    let db_clone = db.clone();
+    let channels: Vec<String> = tokio::task::spawn_blocking(move || -> Result<Vec<String>, Error> {
+        let conn = db_clone.get()?;
+        let mut stmt =
+            conn.prepare("SELECT DISTINCT added_in_channel FROM streams WHERE server_id IS NULL")?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let resolved: Vec<(String, String)> = channels
+        .into_iter()
+        .filter_map(|channel_id| match context.cache.get_channel(&channel_id) {
+            Some(Channel::TextChannel { server, .. }) => Some((channel_id, server)),
+            _ => None,
+        })
+        .collect();
+
+    if resolved.is_empty() {
+        return Ok(());
+    }
+
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        for (channel_id, server_id) in &resolved {
+            conn.execute(
+                "UPDATE streams SET server_id = ?1 WHERE added_in_channel = ?2 AND server_id IS NULL",
+                params![server_id, channel_id],
+            )?;
+        }
+        println!("ℹ Resolved server for {} channels", resolved.len());
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
+}
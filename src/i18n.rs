@@ -0,0 +1,102 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::sync::LazyLock;
+use unic_langid::LanguageIdentifier;
+
+/// Languages the bot can reply in. Catalogs live in `locales/` and are
+/// compiled into the binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    PtBr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::De, Locale::PtBr];
+
+    pub fn parse(code: &str) -> Option<Self> {
+        match code.to_lowercase().replace('_', "-").as_str() {
+            "en" | "en-us" | "en-gb" => Some(Locale::En),
+            "de" | "de-de" | "de-at" | "de-ch" => Some(Locale::De),
+            "pt" | "pt-br" => Some(Locale::PtBr),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::PtBr => "pt-BR",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.ftl"),
+            Locale::De => include_str!("../locales/de.ftl"),
+            Locale::PtBr => include_str!("../locales/pt-BR.ftl"),
+        }
+    }
+}
+
+type Bundle = FluentBundle<FluentResource>;
+
+static BUNDLES: LazyLock<Vec<(Locale, Bundle)>> = LazyLock::new(|| {
+    Locale::ALL
+        .iter()
+        .map(|locale| {
+            let langid: LanguageIdentifier = locale.code().parse().expect("valid language id");
+            let mut bundle = Bundle::new_concurrent(vec![langid]);
+            // Unicode isolation marks show up as stray characters in Stoat
+            bundle.set_use_isolating(false);
+
+            let resource = FluentResource::try_new(locale.source().to_string())
+                .unwrap_or_else(|(_, errors)| {
+                    panic!("invalid {} catalog: {:?}", locale.code(), errors)
+                });
+            bundle
+                .add_resource(resource)
+                .unwrap_or_else(|errors| panic!("duplicate {} messages: {:?}", locale.code(), errors));
+            (*locale, bundle)
+        })
+        .collect()
+});
+
+fn bundle(locale: Locale) -> &'static Bundle {
+    &BUNDLES.iter().find(|(l, _)| *l == locale).expect("all locales loaded").1
+}
+
+fn format(locale: Locale, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let bundle = bundle(locale);
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        eprintln!("✗ Failed to format {} ({}): {:?}", id, locale.code(), errors);
+    }
+    Some(text.into_owned())
+}
+
+/// Looks up a message in the given language, falling back to English and
+/// finally to the message id itself.
+pub fn tr(locale: Locale, id: &str, args: Option<&FluentArgs>) -> String {
+    format(locale, id, args)
+        .or_else(|| format(Locale::En, id, args))
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// `t!(locale, "message-id")` or `t!(locale, "message-id", name = value, ...)`
+macro_rules! t {
+    ($locale:expr, $id:expr) => {
+        $crate::i18n::tr($locale, $id, None)
+    };
+    ($locale:expr, $id:expr, $($key:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($key), $value);)+
+        $crate::i18n::tr($locale, $id, Some(&args))
+    }};
+}
+pub(crate) use t;
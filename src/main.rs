@@ -3,19 +3,25 @@ mod config;
 mod db;
 mod error;
 mod events;
+mod i18n;
+mod notify;
+mod quota;
+mod settings;
 mod state;
 mod twitch;
 
 use dotenv::dotenv;
 use parking_lot::RwLock;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::sync::Arc;
 use stoat::Client;
+use stoat::types::StoatConfig;
+use tokio::sync::Notify;
 
 use config::Config;
 use error::Error;
-use state::State;
+use state::{EventSubStatus, State};
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
@@ -31,37 +37,79 @@ async fn main() -> Result<(), Error> {
     let db = db::init_database()?;
 
     let online_channels = Arc::new(RwLock::new(HashSet::new()));
+    let offline_since = Arc::new(RwLock::new(HashMap::new()));
+    let outbox_notify = Arc::new(Notify::new());
     let session_id = Arc::new(RwLock::new(None));
+    let prefixes = Arc::new(RwLock::new(HashMap::new()));
+    let eventsub = Arc::new(RwLock::new(EventSubStatus::default()));
 
     let config = Config::default();
-    println!("ℹ Max streams per user: {}", config.max_streams_per_user);
+    println!(
+        "ℹ Stream quotas: {} per user, {} per server, {} per channel",
+        config.max_streams_per_user, config.max_streams_per_server, config.max_streams_per_channel
+    );
+    println!("ℹ Default flap cooldown: {} min", config.flap_cooldown_minutes);
+    println!("ℹ Stoat API: {}", config.stoat_api_url);
 
     let state = State {
         db: db.clone(),
-        online_channels: online_channels.clone(),
-        client_id: twitch_client_id.clone(),
-        session_id: session_id.clone(),
-        twitch_token: twitch_bot_token.clone(),
+        online_channels,
+        offline_since,
+        client_id: twitch_client_id,
+        session_id,
+        twitch_token: twitch_bot_token,
+        stoat_token: stoat_token.clone(),
         config,
+        outbox_notify,
+        prefixes,
+        eventsub,
+        started_at: chrono::Utc::now(),
     };
 
     let commands = commands::create_handler(state.clone());
 
     let events = events::Events {
         command_handler: commands,
+        db: db.clone(),
     };
 
-    let mut client = Client::new(events).await?;
+    // Fails if the endpoint does not serve Stoat's root metadata
+    let mut client = Client::new_with_api_url(events, state.config.stoat_api_url.clone())
+        .await
+        .inspect_err(|e| {
+            eprintln!(
+                "✗ {} did not respond as a Stoat API: {}",
+                state.config.stoat_api_url, e
+            )
+        })?;
+
+    if let Some(ws_url) = &state.config.stoat_ws_url {
+        let api_config = Arc::new(StoatConfig {
+            ws: ws_url.clone(),
+            ..(*client.http.api_config).clone()
+        });
+        client.http.api_config = api_config.clone();
+        client.state.api_config = api_config;
+    }
+    println!("ℹ Stoat WebSocket: {}", client.http.api_config.ws);
+
+    // Authenticate now so notifications share the command client's HTTP handle
+    client.start(&stoat_token).await?;
+
+    // Deliver queued notifications in background, starting with any left from the last run
+    let outbox_handle = notify::start_outbox_task(client.http.clone(), state.clone());
+
+    // Deliver queued quiet hours summaries in background
+    let quiet_hours_handle = notify::start_quiet_hours_task(state.clone());
+
+    // Poll followed Twitch categories in background
+    let category_poll_handle = twitch::start_category_poll_task(state.clone());
+
+    // Follow Twitch team membership in background
+    let team_sync_handle = twitch::start_team_sync_task(state.clone());
 
     // Start EventSub in background
-    let eventsub_handle = twitch::start_eventsub_task(
-        stoat_token.clone(),
-        twitch_bot_token,
-        twitch_client_id,
-        db,
-        online_channels,
-        session_id,
-    );
+    let eventsub_handle = twitch::start_eventsub_task(state);
 
     // Run with graceful shutdown
     tokio::select! {
@@ -72,6 +120,10 @@ async fn main() -> Result<(), Error> {
         _ = tokio::signal::ctrl_c() => {
             println!("ℹ Shutting down...");
             eventsub_handle.abort();
+            team_sync_handle.abort();
+            category_poll_handle.abort();
+            quiet_hours_handle.abort();
+            outbox_handle.abort();
             Ok(())
         }
     }
@@ -0,0 +1,37 @@
+use crate::twitch::types::HelixStream;
+
+/// Splits a stored comma-separated filter into its non-empty, trimmed entries.
+pub fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Whether a stream passes a subscription's game and title keyword filters.
+///
+/// Both checks are case-insensitive. If the stream details couldn't be fetched
+/// the notification is sent anyway, a missed announcement is worse than an extra one.
+pub fn matches(games: Option<&str>, keywords: Option<&str>, stream: Option<&HelixStream>) -> bool {
+    let Some(stream) = stream else {
+        return true;
+    };
+
+    let game_ok = match games.map(parse_list) {
+        Some(games) if !games.is_empty() => games
+            .iter()
+            .any(|game| game.eq_ignore_ascii_case(&stream.game_name)),
+        _ => true,
+    };
+
+    let title = stream.title.to_lowercase();
+    let keyword_ok = match keywords.map(parse_list) {
+        Some(keywords) if !keywords.is_empty() => keywords
+            .iter()
+            .any(|keyword| title.contains(&keyword.to_lowercase())),
+        _ => true,
+    };
+
+    game_ok && keyword_ok
+}
@@ -0,0 +1,87 @@
+pub mod filter;
+pub mod outbox;
+pub mod quiet_hours;
+pub mod send_queue;
+pub mod template;
+
+pub use outbox::start_outbox_task;
+pub use quiet_hours::{QuietHours, QuietMode, start_quiet_hours_task};
+
+use revolt_result::ErrorType;
+use std::time::Duration;
+use stoat::HttpClient;
+use stoat::types::{Message, SendableEmbed};
+
+/// How a failed send should be handled by the outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Wait and try again without counting an attempt
+    RateLimited { retry_after: Duration },
+    /// A previous attempt already went through
+    AlreadySent,
+    /// The target channel is gone or the bot may not post there
+    BrokenTarget,
+    /// The request itself is invalid and will never succeed
+    Permanent,
+    /// Network or server trouble, retry with backoff
+    Transient,
+}
+
+pub fn classify_error(error: &stoat::Error) -> FailureKind {
+    match error {
+        stoat::Error::RatelimitReached(failure) => FailureKind::RateLimited {
+            retry_after: Duration::from_millis(failure.retry_after as u64),
+        },
+        stoat::Error::HttpError(e) => match e.error_type {
+            ErrorType::DuplicateNonce => FailureKind::AlreadySent,
+            // Only errors about the channel itself disable its streams, a generic
+            // 404 can come from anything the request refers to
+            ErrorType::UnknownChannel | ErrorType::UnknownServer | ErrorType::MissingPermission { .. } => {
+                FailureKind::BrokenTarget
+            }
+            ErrorType::InternalError
+            | ErrorType::DatabaseError { .. }
+            | ErrorType::ProxyError
+            | ErrorType::NotFound
+            | ErrorType::NotElevated => FailureKind::Transient,
+            _ => FailureKind::Permanent,
+        },
+        _ => FailureKind::Transient,
+    }
+}
+
+/// Posts a message with an `Idempotency-Key` header so Stoat drops repeated
+/// sends. The stoat-rs message builder can't set headers, so this request
+/// goes through the shared client's connection pool and token directly.
+pub async fn send_message(
+    http: &HttpClient,
+    channel_id: &str,
+    content: &str,
+    embed: Option<&SendableEmbed>,
+    idempotency_key: &str,
+) -> Result<Message, stoat::Error> {
+    let mut payload = serde_json::json!({ "content": content, "nonce": idempotency_key });
+    if let Some(embed) = embed {
+        payload["embeds"] = serde_json::json!([embed]);
+    }
+
+    let mut request = http
+        .inner
+        .post(format!("{}/channels/{}/messages", http.base, channel_id))
+        .header("Accept", "application/json")
+        .header("Idempotency-Key", idempotency_key)
+        .json(&payload);
+    if let Some(token) = &http.token {
+        request = request.header("x-bot-token", token);
+    }
+
+    let response = request.send().await?;
+    let status = response.status();
+    if status.as_u16() == 429 {
+        return Err(stoat::Error::RatelimitReached(response.json().await?));
+    }
+    if status.is_client_error() || status.is_server_error() {
+        return Err(stoat::Error::HttpError(response.json().await?));
+    }
+    Ok(response.json().await?)
+}
@@ -0,0 +1,221 @@
+use crate::config::{
+    OUTBOX_MAX_ATTEMPTS, OUTBOX_MAX_BACKOFF_SECS, OUTBOX_POLL_SECS, OUTBOX_RETENTION_DAYS,
+    OUTBOX_RETRY_BASE_SECS,
+};
+use crate::error::Error;
+use crate::notify::send_queue::SendQueue;
+use crate::state::State;
+
+use chrono::Utc;
+use rusqlite::params;
+use stoat::HttpClient;
+use stoat::types::SendableEmbed;
+
+#[derive(Debug, Clone)]
+pub struct OutboxEntry {
+    pub id: i64,
+    pub idempotency_key: String,
+    pub channel_id: String,
+    pub content: String,
+    pub embed: Option<SendableEmbed>,
+    pub attempts: u32,
+    pub next_attempt_at: i64,
+}
+
+/// Stores a notification for delivery. Entries with an existing idempotency key
+/// are ignored, so a redelivered Twitch event never produces a second message.
+pub async fn enqueue(
+    state: &State,
+    idempotency_key: String,
+    channel_id: String,
+    content: String,
+    embed: Option<SendableEmbed>,
+) -> Result<(), Error> {
+    let embed = embed
+        .map(|e| serde_json::to_string(&e))
+        .transpose()
+        .map_err(|e| Error::DatabaseError(e.to_string()))?;
+
+    let db = state.db.clone();
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "INSERT OR IGNORE INTO outbox (idempotency_key, channel_id, content, embed, state, attempts, next_attempt_at, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'pending', 0, ?5, ?6)",
+            params![
+                idempotency_key,
+                channel_id,
+                content,
+                embed,
+                Utc::now().timestamp(),
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    state.outbox_notify.notify_one();
+    Ok(())
+}
+
+pub fn start_outbox_task(http: HttpClient, state: State) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let queue = SendQueue::new(http);
+
+        loop {
+            // Entries left pending by a previous run are due immediately
+            if let Err(e) = drain(&queue, &state).await {
+                eprintln!("✗ Outbox error: {}", e);
+            }
+
+            tokio::select! {
+                _ = state.outbox_notify.notified() => {}
+                _ = tokio::time::sleep(tokio::time::Duration::from_secs(OUTBOX_POLL_SECS)) => {}
+            }
+        }
+    })
+}
+
+async fn drain(queue: &SendQueue, state: &State) -> Result<(), Error> {
+    let db = state.db.clone();
+    let entries: Vec<OutboxEntry> = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "DELETE FROM outbox WHERE state != 'pending' AND created_at < ?1",
+            params![(Utc::now() - chrono::Duration::days(OUTBOX_RETENTION_DAYS)).to_rfc3339()],
+        )?;
+
+        // Not-yet-due entries are loaded too, so later messages wait behind them
+        let mut stmt = conn.prepare(
+            "SELECT id, idempotency_key, channel_id, content, embed, attempts, next_attempt_at FROM outbox
+             WHERE state = 'pending' ORDER BY id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(OutboxEntry {
+                id: row.get(0)?,
+                idempotency_key: row.get(1)?,
+                channel_id: row.get(2)?,
+                content: row.get(3)?,
+                embed: row
+                    .get::<_, Option<String>>(4)?
+                    .and_then(|json| serde_json::from_str(&json).ok()),
+                attempts: row.get(5)?,
+                next_attempt_at: row.get(6)?,
+            })
+        })?;
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    queue.deliver(entries, state).await;
+    Ok(())
+}
+
+pub async fn mark_sent(
+    state: &State,
+    entry: &OutboxEntry,
+    message_id: Option<String>,
+) -> Result<(), Error> {
+    let db = state.db.clone();
+    let id = entry.id;
+    let attempts = entry.attempts + 1;
+
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "UPDATE outbox SET state = 'sent', attempts = ?1, last_error = NULL, message_id = ?2 WHERE id = ?3",
+            params![attempts, message_id, id],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
+}
+
+/// Records a failed attempt. Returns `true` if the entry will be retried.
+pub async fn mark_failed(
+    state: &State,
+    entry: &OutboxEntry,
+    error: String,
+    permanent: bool,
+) -> Result<bool, Error> {
+    let attempts = entry.attempts + 1;
+    let give_up = permanent || attempts >= OUTBOX_MAX_ATTEMPTS;
+
+    if give_up {
+        eprintln!(
+            "✗ Giving up on notification to {} after {} attempts: {}",
+            entry.channel_id, attempts, error
+        );
+    } else {
+        eprintln!(
+            "✗ Failed to send notification to {} (attempt {}): {}",
+            entry.channel_id, attempts, error
+        );
+    }
+
+    let delay = (OUTBOX_RETRY_BASE_SECS * 2i64.pow(attempts)).min(OUTBOX_MAX_BACKOFF_SECS);
+    let next_attempt_at = Utc::now().timestamp() + delay;
+    let new_state = if give_up { "failed" } else { "pending" };
+
+    let db = state.db.clone();
+    let id = entry.id;
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "UPDATE outbox SET state = ?1, attempts = ?2, next_attempt_at = ?3, last_error = ?4 WHERE id = ?5",
+            params![new_state, attempts, next_attempt_at, error, id],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    Ok(!give_up)
+}
+
+/// Pushes an entry back without counting an attempt, used when rate limited.
+pub async fn defer(state: &State, entry: &OutboxEntry, next_attempt_at: i64) -> Result<(), Error> {
+    let db = state.db.clone();
+    let id = entry.id;
+
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "UPDATE outbox SET next_attempt_at = ?1 WHERE id = ?2",
+            params![next_attempt_at, id],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
+}
+
+/// Stops notifying a channel the bot can no longer post in. Its streams are
+/// disabled rather than deleted so they can be re-enabled once fixed.
+pub async fn disable_channel(state: &State, channel_id: &str) -> Result<(), Error> {
+    let db = state.db.clone();
+    let channel_id = channel_id.to_string();
+
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "UPDATE streams SET enabled = 0 WHERE added_in_channel = ?1",
+            params![channel_id],
+        )?;
+        conn.execute(
+            "UPDATE outbox SET state = 'failed', last_error = 'channel disabled' WHERE channel_id = ?1 AND state = 'pending'",
+            params![channel_id],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
+}
@@ -0,0 +1,371 @@
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::outbox;
+use crate::settings::load_server_settings;
+use crate::state::State;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use rusqlite::params;
+use std::collections::BTreeMap;
+
+pub const QUIET_HOURS_CHECK_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuietMode {
+    /// Notifications are not sent at all
+    Drop,
+    /// Notifications are sent with role mentions removed
+    Silent,
+    /// Notifications are held back and posted as one summary when the window ends
+    Queue,
+}
+
+impl QuietMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "drop" => Some(Self::Drop),
+            "silent" => Some(Self::Silent),
+            "queue" => Some(Self::Queue),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Drop => "drop",
+            Self::Silent => "silent",
+            Self::Queue => "queue",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuietHoursError {
+    InvalidStart(String),
+    InvalidEnd(String),
+    SameStartEnd,
+    UnknownTimezone(String),
+    UnknownMode(String),
+}
+
+impl QuietHoursError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            QuietHoursError::InvalidStart(value) => {
+                t!(locale, "quiet-invalid-start", value = value.as_str())
+            }
+            QuietHoursError::InvalidEnd(value) => {
+                t!(locale, "quiet-invalid-end", value = value.as_str())
+            }
+            QuietHoursError::SameStartEnd => t!(locale, "quiet-same-start-end"),
+            QuietHoursError::UnknownTimezone(value) => {
+                t!(locale, "quiet-unknown-timezone", value = value.as_str())
+            }
+            QuietHoursError::UnknownMode(value) => {
+                t!(locale, "quiet-unknown-mode", value = value.as_str())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub timezone: Tz,
+    pub mode: QuietMode,
+}
+
+impl QuietHours {
+    pub fn parse(
+        start: &str,
+        end: &str,
+        timezone: &str,
+        mode: &str,
+    ) -> Result<Self, QuietHoursError> {
+        let start = NaiveTime::parse_from_str(start, "%H:%M")
+            .map_err(|_| QuietHoursError::InvalidStart(start.to_string()))?;
+        let end = NaiveTime::parse_from_str(end, "%H:%M")
+            .map_err(|_| QuietHoursError::InvalidEnd(end.to_string()))?;
+        if start == end {
+            return Err(QuietHoursError::SameStartEnd);
+        }
+        let timezone: Tz = timezone
+            .parse()
+            .map_err(|_| QuietHoursError::UnknownTimezone(timezone.to_string()))?;
+        let mode = QuietMode::parse(mode)
+            .ok_or_else(|| QuietHoursError::UnknownMode(mode.to_string()))?;
+
+        Ok(Self {
+            start,
+            end,
+            timezone,
+            mode,
+        })
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        let local = now.with_timezone(&self.timezone).time();
+        if self.start < self.end {
+            local >= self.start && local < self.end
+        } else {
+            // Window wraps around midnight, e.g. 22:00-07:00
+            local >= self.start || local < self.end
+        }
+    }
+}
+
+impl std::fmt::Display for QuietHours {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{} {} ({})",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M"),
+            self.timezone,
+            self.mode.as_str()
+        )
+    }
+}
+
+/// Removes role mentions (`<%ROLE_ID>`) and mass mentions from a message.
+pub fn strip_role_mentions(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut rest = message;
+
+    while let Some(start) = rest.find("<%") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find('>') {
+            Some(end) => rest = &rest[start + end + 1..],
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result.replace("@everyone", "everyone").replace("@online", "online")
+}
+
+pub async fn queue_notification(
+    state: &State,
+    server_id: &str,
+    channel_id: &str,
+    login: &str,
+    display_name: &str,
+) -> Result<(), Error> {
+    let db = state.db.clone();
+    let server_id = server_id.to_string();
+    let channel_id = channel_id.to_string();
+    let login = login.to_string();
+    let display_name = display_name.to_string();
+
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        let conn = db.get()?;
+        conn.execute(
+            "INSERT INTO quiet_queue (server_id, channel_id, channel_name, display_name, went_live_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![server_id, channel_id, login, display_name, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))?
+}
+
+pub fn start_quiet_hours_task(state: State) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(QUIET_HOURS_CHECK_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = flush_ended_windows(&state).await {
+                eprintln!("✗ Failed to deliver quiet hours summary: {}", e);
+            }
+        }
+    })
+}
+
+struct QueuedNotification {
+    id: i64,
+    channel_id: String,
+    channel_name: String,
+    display_name: String,
+    went_live_at: String,
+}
+
+// Posts one summary per Stoat channel for every server whose quiet window has ended.
+async fn flush_ended_windows(state: &State) -> Result<(), Error> {
+    let db = state.db.clone();
+    let due: Vec<(Option<QuietHours>, Locale, Vec<QueuedNotification>)> =
+        tokio::task::spawn_blocking(move || -> Result<_, Error> {
+            let conn = db.get()?;
+            let mut stmt = conn.prepare("SELECT DISTINCT server_id FROM quiet_queue")?;
+            let servers: Vec<String> = stmt
+                .query_map([], |row| row.get(0))?
+                .filter_map(|r| r.ok())
+                .collect();
+
+            let mut due = Vec::new();
+            for server_id in servers {
+                let settings = load_server_settings(&conn, &server_id)?;
+                let mut stmt = conn.prepare(
+                    "SELECT id, channel_id, channel_name, display_name, went_live_at FROM quiet_queue WHERE server_id = ?1 ORDER BY id",
+                )?;
+                let entries = stmt
+                    .query_map(params![server_id], |row| {
+                        Ok(QueuedNotification {
+                            id: row.get(0)?,
+                            channel_id: row.get(1)?,
+                            channel_name: row.get(2)?,
+                            display_name: row.get(3)?,
+                            went_live_at: row.get(4)?,
+                        })
+                    })?
+                    .filter_map(|r| r.ok())
+                    .collect();
+                due.push((settings.quiet_hours(), settings.locale(), entries));
+            }
+            Ok(due)
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let now = Utc::now();
+    for (quiet_hours, locale, entries) in due {
+        if quiet_hours.as_ref().is_some_and(|q| q.is_active(now)) {
+            continue;
+        }
+
+        let mut by_channel: BTreeMap<String, Vec<QueuedNotification>> = BTreeMap::new();
+        for entry in entries {
+            by_channel.entry(entry.channel_id.clone()).or_default().push(entry);
+        }
+
+        for (channel_id, entries) in by_channel {
+            let mut summary = format!("**{}**\n", t!(locale, "quiet-summary-title"));
+            for entry in &entries {
+                let time = DateTime::parse_from_rfc3339(&entry.went_live_at)
+                    .ok()
+                    .map(|t| match &quiet_hours {
+                        Some(q) => t.with_timezone(&q.timezone).format("%H:%M").to_string(),
+                        None => t.format("%H:%M UTC").to_string(),
+                    })
+                    .unwrap_or_default();
+                summary.push_str(&format!(
+                    "- {} ({}) https://twitch.tv/{}\n",
+                    entry.display_name, time, entry.channel_name
+                ));
+            }
+
+            // Keyed by the first queued entry, so a retry after a crash is not posted twice
+            let idempotency_key = format!("quiet:{}:{}", channel_id, entries[0].id);
+            outbox::enqueue(state, idempotency_key, channel_id, summary, None).await?;
+
+            let db = state.db.clone();
+            let ids: Vec<i64> = entries.iter().map(|e| e.id).collect();
+            tokio::task::spawn_blocking(move || -> Result<(), Error> {
+                let conn = db.get()?;
+                for id in ids {
+                    conn.execute("DELETE FROM quiet_queue WHERE id = ?1", params![id])?;
+                }
+                Ok(())
+            })
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().unwrap()
+    }
+
+    fn quiet(start: &str, end: &str, timezone: &str) -> QuietHours {
+        QuietHours::parse(start, end, timezone, "drop").unwrap()
+    }
+
+    #[test]
+    fn daytime_window_includes_start_but_not_end() {
+        let hours = quiet("09:00", "17:00", "UTC");
+        assert!(!hours.is_active(utc("2024-01-10T08:59:00Z")));
+        assert!(hours.is_active(utc("2024-01-10T09:00:00Z")));
+        assert!(hours.is_active(utc("2024-01-10T16:59:00Z")));
+        assert!(!hours.is_active(utc("2024-01-10T17:00:00Z")));
+    }
+
+    #[test]
+    fn overnight_window_wraps_around_midnight() {
+        let hours = quiet("22:00", "07:00", "UTC");
+        assert!(hours.is_active(utc("2024-01-10T22:00:00Z")));
+        assert!(hours.is_active(utc("2024-01-10T23:59:00Z")));
+        assert!(hours.is_active(utc("2024-01-11T00:00:00Z")));
+        assert!(hours.is_active(utc("2024-01-11T06:59:00Z")));
+        assert!(!hours.is_active(utc("2024-01-11T07:00:00Z")));
+        assert!(!hours.is_active(utc("2024-01-11T12:00:00Z")));
+    }
+
+    #[test]
+    fn window_follows_the_local_offset_across_dst() {
+        let hours = quiet("22:00", "07:00", "America/New_York");
+        // 22:00 EST and 23:00 EDT
+        assert!(hours.is_active(utc("2024-01-10T03:00:00Z")));
+        assert!(hours.is_active(utc("2024-07-10T03:00:00Z")));
+        // 06:30 EST is quiet, 07:30 EDT is not
+        assert!(hours.is_active(utc("2024-01-10T11:30:00Z")));
+        assert!(!hours.is_active(utc("2024-07-10T11:30:00Z")));
+    }
+
+    #[test]
+    fn skipped_and_repeated_local_hours() {
+        // Berlin skips 02:00-03:00 on 2024-03-31
+        let hours = quiet("01:30", "02:30", "Europe/Berlin");
+        assert!(hours.is_active(utc("2024-03-31T00:45:00Z")));
+        assert!(!hours.is_active(utc("2024-03-31T01:00:00Z")));
+
+        // and goes through 02:00-03:00 twice on 2024-10-27
+        let hours = quiet("02:00", "02:30", "Europe/Berlin");
+        assert!(hours.is_active(utc("2024-10-27T00:15:00Z")));
+        assert!(hours.is_active(utc("2024-10-27T01:15:00Z")));
+        assert!(!hours.is_active(utc("2024-10-27T01:45:00Z")));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let error = |start, end, timezone, mode| QuietHours::parse(start, end, timezone, mode).unwrap_err();
+        assert_eq!(
+            error("25:00", "07:00", "UTC", "drop"),
+            QuietHoursError::InvalidStart("25:00".to_string())
+        );
+        assert_eq!(
+            error("22:00", "7pm", "UTC", "drop"),
+            QuietHoursError::InvalidEnd("7pm".to_string())
+        );
+        assert_eq!(error("22:00", "22:00", "UTC", "drop"), QuietHoursError::SameStartEnd);
+        assert_eq!(
+            error("22:00", "07:00", "Mars/Olympus", "drop"),
+            QuietHoursError::UnknownTimezone("Mars/Olympus".to_string())
+        );
+        assert_eq!(
+            error("22:00", "07:00", "UTC", "loud"),
+            QuietHoursError::UnknownMode("loud".to_string())
+        );
+    }
+
+    #[test]
+    fn strips_role_and_mass_mentions() {
+        assert_eq!(strip_role_mentions("<%01ROLE> x is live"), " x is live");
+        assert_eq!(strip_role_mentions("@everyone @online hi"), "everyone online hi");
+        assert_eq!(strip_role_mentions("a <%unclosed"), "a <%unclosed");
+        assert_eq!(strip_role_mentions("<@01USER> stays"), "<@01USER> stays");
+    }
+}
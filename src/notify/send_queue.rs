@@ -0,0 +1,140 @@
+use crate::config::{MAX_CONCURRENT_SENDS, MAX_RATE_LIMIT_RETRIES};
+use crate::notify::outbox::{self, OutboxEntry};
+use crate::notify::{FailureKind, classify_error, send_message};
+use crate::state::State;
+
+use chrono::Utc;
+use futures_util::StreamExt;
+use std::collections::BTreeMap;
+use stoat::HttpClient;
+
+/// Sends outbox entries to Stoat. Each channel is its own rate limit bucket
+/// ("messaging" in Stoat), so channels are delivered concurrently while the
+/// entries within one channel go out strictly in order. A 429 is waited out
+/// before the same entry is sent again.
+pub struct SendQueue {
+    http: HttpClient,
+}
+
+impl SendQueue {
+    pub fn new(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    pub async fn deliver(&self, entries: Vec<OutboxEntry>, state: &State) {
+        let mut by_channel: BTreeMap<String, Vec<OutboxEntry>> = BTreeMap::new();
+        for entry in entries {
+            by_channel.entry(entry.channel_id.clone()).or_default().push(entry);
+        }
+
+        let now = Utc::now().timestamp();
+        let depth: usize = by_channel.values().map(Vec::len).sum();
+        let due = by_channel
+            .values()
+            .flatten()
+            .filter(|e| e.next_attempt_at <= now)
+            .count();
+        if due == 0 {
+            return;
+        }
+
+        println!(
+            "ℹ Send queue: {} due, {} pending in {} channels",
+            due,
+            depth,
+            by_channel.len()
+        );
+
+        futures_util::stream::iter(by_channel)
+            .for_each_concurrent(MAX_CONCURRENT_SENDS, |(channel_id, entries)| async move {
+                self.deliver_channel(&channel_id, entries, state).await;
+            })
+            .await;
+    }
+
+    async fn deliver_channel(&self, channel_id: &str, entries: Vec<OutboxEntry>, state: &State) {
+        let total = entries.len();
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            // An earlier entry is waiting for a retry; keep the channel in order
+            if entry.next_attempt_at > Utc::now().timestamp() {
+                return;
+            }
+
+            let mut rate_limited = 0;
+            loop {
+                let result = send_message(
+                    &self.http,
+                    channel_id,
+                    &entry.content,
+                    entry.embed.as_ref(),
+                    &entry.idempotency_key,
+                )
+                .await;
+
+                let error = match result {
+                    Ok(message) => {
+                        if let Err(e) = outbox::mark_sent(state, &entry, Some(message.id)).await {
+                            eprintln!("✗ Failed to update outbox: {}", e);
+                        }
+                        break;
+                    }
+                    Err(e) => e,
+                };
+
+                match classify_error(&error) {
+                    FailureKind::RateLimited { retry_after } => {
+                        rate_limited += 1;
+                        eprintln!(
+                            "⚠ Rate limited on {}, backing off {}ms ({} left in channel)",
+                            channel_id,
+                            retry_after.as_millis(),
+                            total - index
+                        );
+
+                        if rate_limited >= MAX_RATE_LIMIT_RETRIES {
+                            let next_attempt_at =
+                                Utc::now().timestamp() + retry_after.as_secs() as i64 + 1;
+                            if let Err(e) = outbox::defer(state, &entry, next_attempt_at).await {
+                                eprintln!("✗ Failed to update outbox: {}", e);
+                            }
+                            return;
+                        }
+
+                        tokio::time::sleep(retry_after).await;
+                    }
+                    FailureKind::AlreadySent => {
+                        if let Err(e) = outbox::mark_sent(state, &entry, None).await {
+                            eprintln!("✗ Failed to update outbox: {}", e);
+                        }
+                        break;
+                    }
+                    FailureKind::BrokenTarget => {
+                        eprintln!(
+                            "✗ Cannot post in {}, disabling its streams: {:?}",
+                            channel_id, error
+                        );
+                        if let Err(e) = outbox::disable_channel(state, channel_id).await {
+                            eprintln!("✗ Failed to disable channel: {}", e);
+                        }
+                        return;
+                    }
+                    kind => {
+                        let permanent = kind == FailureKind::Permanent;
+                        match outbox::mark_failed(state, &entry, format!("{:?}", error), permanent)
+                            .await
+                        {
+                            // Dropped entries don't block the rest of the channel
+                            Ok(false) => break,
+                            Ok(true) => return,
+                            Err(e) => {
+                                eprintln!("✗ Failed to update outbox: {}", e);
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
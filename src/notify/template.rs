@@ -0,0 +1,458 @@
+use crate::i18n::{Locale, t};
+use crate::twitch::types::HelixStream;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use stoat::types::SendableEmbed;
+
+/// Twitch purple, used as the embed accent colour.
+const EMBED_COLOUR: &str = "#9146FF";
+
+/// How a notification is posted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageMode {
+    /// Only the rendered template
+    Text,
+    /// The rendered template plus a card with the stream details
+    Embed,
+}
+
+impl MessageMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "text" => Some(MessageMode::Text),
+            "embed" => Some(MessageMode::Embed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageMode::Text => "text",
+            MessageMode::Embed => "embed",
+        }
+    }
+}
+
+/// Placeholders that can appear in a notification template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Login,
+    Name,
+    Title,
+    Game,
+    StartedAt,
+    Viewers,
+    Url,
+    Mention,
+}
+
+impl Field {
+    pub const ALL: [Field; 8] = [
+        Field::Login,
+        Field::Name,
+        Field::Title,
+        Field::Game,
+        Field::StartedAt,
+        Field::Viewers,
+        Field::Url,
+        Field::Mention,
+    ];
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "login" => Some(Field::Login),
+            // `{channel}` predates the template engine and stays valid
+            "name" | "channel" => Some(Field::Name),
+            "title" => Some(Field::Title),
+            "game" => Some(Field::Game),
+            "started_at" => Some(Field::StartedAt),
+            "viewers" => Some(Field::Viewers),
+            "url" => Some(Field::Url),
+            "mention" => Some(Field::Mention),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Field::Login => "login",
+            Field::Name => "name",
+            Field::Title => "title",
+            Field::Game => "game",
+            Field::StartedAt => "started_at",
+            Field::Viewers => "viewers",
+            Field::Url => "url",
+            Field::Mention => "mention",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    UnknownPlaceholder(String),
+    UnclosedPlaceholder,
+    UnclosedSection(String),
+    UnexpectedSectionEnd(String),
+}
+
+impl TemplateError {
+    /// The error as shown to users in a command reply.
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            TemplateError::UnknownPlaceholder(name) => t!(
+                locale,
+                "template-unknown-placeholder",
+                placeholder = format!("{{{}}}", name),
+                available = Template::placeholders()
+            ),
+            TemplateError::UnclosedPlaceholder => t!(locale, "template-unclosed-placeholder"),
+            TemplateError::UnclosedSection(name) => t!(
+                locale,
+                "template-unclosed-section",
+                open = format!("{{?{}}}", name),
+                close = format!("{{/{}}}", name)
+            ),
+            TemplateError::UnexpectedSectionEnd(name) => t!(
+                locale,
+                "template-unexpected-section-end",
+                open = format!("{{?{}}}", name),
+                close = format!("{{/{}}}", name)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Locale::En))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Field(Field),
+    /// Rendered only when the field has a value
+    Section(Field, Vec<Node>),
+}
+
+/// A parsed notification template.
+///
+/// Placeholders are written as `{field}`. A section `{?game}...{/game}` is only
+/// shown when the field is set, and `{{`/`}}` produce literal braces.
+#[derive(Debug, Clone)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+/// Values available to a template when rendering.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateContext {
+    pub login: String,
+    pub name: String,
+    pub title: Option<String>,
+    pub game: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub viewers: Option<u64>,
+    pub mention_role: Option<String>,
+}
+
+impl TemplateContext {
+    /// Builds a context from the EventSub names plus Helix details, if the stream is live.
+    pub fn new(login: &str, name: &str, stream: Option<&HelixStream>) -> Self {
+        Self {
+            login: login.to_string(),
+            name: name.to_string(),
+            title: stream.map(|s| s.title.clone()),
+            game: stream.map(|s| s.game_name.clone()),
+            started_at: stream.map(|s| s.started_at),
+            viewers: stream.map(|s| s.viewer_count),
+            mention_role: None,
+        }
+    }
+
+    /// Placeholder values used to preview a template while the streamer is offline.
+    pub fn sample(login: &str, locale: Locale) -> Self {
+        Self {
+            login: login.to_string(),
+            name: login.to_string(),
+            title: Some(t!(locale, "template-sample-title")),
+            game: Some("Just Chatting".to_string()),
+            started_at: Some(Utc::now()),
+            viewers: Some(42),
+            mention_role: None,
+        }
+    }
+
+    /// A card with the stream's title, category and viewer count. The
+    /// rendered template stays in the message content so role mentions still ping.
+    pub fn embed(&self, locale: Locale) -> SendableEmbed {
+        let mut details = Vec::new();
+        if let Some(game) = self.game.as_deref().filter(|g| !g.is_empty()) {
+            details.push(t!(locale, "embed-playing", game = escape_markdown(game)));
+        }
+        if let Some(viewers) = self.viewers {
+            details.push(t!(locale, "embed-viewers", count = viewers));
+        }
+
+        SendableEmbed {
+            icon_url: None,
+            url: Some(format!("https://twitch.tv/{}", self.login)),
+            title: Some(
+                self.title
+                    .clone()
+                    .filter(|t| !t.is_empty())
+                    .unwrap_or_else(|| t!(locale, "embed-live", name = self.name.as_str()))
+                    .chars()
+                    .take(100)
+                    .collect(),
+            ),
+            description: (!details.is_empty()).then(|| details.join(" · ")),
+            media: None,
+            colour: Some(EMBED_COLOUR.to_string()),
+        }
+    }
+
+    fn value(&self, field: Field) -> Option<String> {
+        match field {
+            Field::Login => Some(escape_markdown(&self.login)),
+            Field::Name => Some(escape_markdown(&self.name)),
+            Field::Title => self.title.as_deref().map(escape_markdown),
+            Field::Game => self.game.as_deref().map(escape_markdown),
+            Field::StartedAt => self
+                .started_at
+                .map(|t| t.format("%H:%M UTC").to_string()),
+            Field::Viewers => self.viewers.map(|v| v.to_string()),
+            Field::Url => Some(format!("https://twitch.tv/{}", self.login)),
+            Field::Mention => self.mention_role.as_ref().map(|id| format!("<%{}>", id)),
+        }
+        .filter(|v| !v.is_empty())
+    }
+}
+
+impl Template {
+    pub fn parse(input: &str) -> Result<Self, TemplateError> {
+        let mut stack: Vec<(Field, Vec<Node>)> = Vec::new();
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut text = String::new();
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut tag = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => tag.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder),
+                        }
+                    }
+
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+
+                    let (kind, name) = match tag.chars().next() {
+                        Some(c @ ('?' | '/')) => (Some(c), tag[1..].trim()),
+                        _ => (None, tag.trim()),
+                    };
+                    let field = Field::parse(name)
+                        .ok_or_else(|| TemplateError::UnknownPlaceholder(name.to_string()))?;
+
+                    match kind {
+                        Some('?') => {
+                            stack.push((field, std::mem::take(&mut nodes)));
+                        }
+                        Some(_) => match stack.pop() {
+                            Some((open, parent)) if open == field => {
+                                let children = std::mem::replace(&mut nodes, parent);
+                                nodes.push(Node::Section(field, children));
+                            }
+                            _ => return Err(TemplateError::UnexpectedSectionEnd(name.to_string())),
+                        },
+                        None => nodes.push(Node::Field(field)),
+                    }
+                }
+                c => text.push(c),
+            }
+        }
+
+        if let Some((field, _)) = stack.pop() {
+            return Err(TemplateError::UnclosedSection(field.name().to_string()));
+        }
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// The built-in notification text for a language, in template syntax.
+    pub fn default_source(locale: Locale) -> String {
+        // Translators write Fluent variables, which are turned back into placeholders here
+        t!(locale, "notification-default", name = "{name}", url = "{url}")
+    }
+
+    pub fn default_template(locale: Locale) -> Self {
+        Self::parse(&Self::default_source(locale))
+            .or_else(|_| Self::parse(&Self::default_source(Locale::En)))
+            .expect("default template is valid")
+    }
+
+    /// All placeholders, formatted for help and error messages.
+    pub fn placeholders() -> String {
+        Field::ALL
+            .iter()
+            .map(|field| format!("`{{{}}}`", field.name()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn render(&self, ctx: &TemplateContext) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, ctx, &mut out);
+        out
+    }
+}
+
+fn render_nodes(nodes: &[Node], ctx: &TemplateContext, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Field(field) => {
+                if let Some(value) = ctx.value(*field) {
+                    out.push_str(&value);
+                }
+            }
+            Node::Section(field, children) => {
+                if ctx.value(*field).is_some() {
+                    render_nodes(children, ctx, out);
+                }
+            }
+        }
+    }
+}
+
+/// Escapes Stoat markdown, mentions and emoji syntax in streamer-controlled text.
+pub fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '<' | '#' | '[' | ']' | '(' | ')' | '$'
+                | ':' | '@' | '!' | '-'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> TemplateContext {
+        TemplateContext {
+            login: "some_streamer".to_string(),
+            name: "Some_Streamer".to_string(),
+            title: Some("Speedrun *any%*".to_string()),
+            game: None,
+            started_at: None,
+            viewers: Some(7),
+            mention_role: Some("01ROLE".to_string()),
+        }
+    }
+
+    fn render(source: &str) -> String {
+        Template::parse(source).unwrap().render(&context())
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        assert_eq!(render("{viewers} watching {url}"), "7 watching https://twitch.tv/some_streamer");
+        assert_eq!(render("{mention} live"), "<%01ROLE> live");
+    }
+
+    #[test]
+    fn channel_is_an_alias_for_name() {
+        assert_eq!(render("{channel}"), render("{name}"));
+    }
+
+    #[test]
+    fn escapes_streamer_text() {
+        assert_eq!(render("{login}"), "some\\_streamer");
+        assert_eq!(render("{name}"), "Some\\_Streamer");
+        assert_eq!(render("{title}"), "Speedrun \\*any%\\*");
+    }
+
+    #[test]
+    fn sections_depend_on_their_field() {
+        assert_eq!(render("a{?game} ({game}){/game}b"), "ab");
+        assert_eq!(render("a{?viewers} ({viewers}){/viewers}b"), "a (7)b");
+        assert_eq!(render("{?title}{?game}x{/game}y{/title}"), "y");
+    }
+
+    #[test]
+    fn empty_values_count_as_missing() {
+        let ctx = TemplateContext {
+            game: Some(String::new()),
+            ..context()
+        };
+        let template = Template::parse("[{?game}{game}{/game}]").unwrap();
+        assert_eq!(template.render(&ctx), "[]");
+    }
+
+    #[test]
+    fn doubled_braces_are_literal() {
+        assert_eq!(render("{{name}} {{ }}"), "{name} { }");
+    }
+
+    #[test]
+    fn placeholder_names_are_trimmed() {
+        assert_eq!(render("{ viewers }"), "7");
+    }
+
+    #[test]
+    fn keeps_text_around_placeholders() {
+        assert_eq!(render("line one\n  line two {viewers}!"), "line one\n  line two 7!");
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert_eq!(
+            Template::parse("{nope}").unwrap_err(),
+            TemplateError::UnknownPlaceholder("nope".to_string())
+        );
+        assert_eq!(Template::parse("{name").unwrap_err(), TemplateError::UnclosedPlaceholder);
+        assert_eq!(
+            Template::parse("{?game}x").unwrap_err(),
+            TemplateError::UnclosedSection("game".to_string())
+        );
+        assert_eq!(
+            Template::parse("x{/game}").unwrap_err(),
+            TemplateError::UnexpectedSectionEnd("game".to_string())
+        );
+        assert_eq!(
+            Template::parse("{?game}{/title}").unwrap_err(),
+            TemplateError::UnexpectedSectionEnd("title".to_string())
+        );
+    }
+
+    #[test]
+    fn default_templates_parse() {
+        for locale in [Locale::En, Locale::De, Locale::PtBr] {
+            assert!(Template::parse(&Template::default_source(locale)).is_ok());
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::config::Config;
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::settings::ServerSettings;
+use rusqlite::{Connection, params};
+
+/// Stream limits for one server: the global defaults from `Config` unless
+/// the bot operator has overridden them for this server.
+#[derive(Debug, Clone, Copy)]
+pub struct Quotas {
+    pub per_user: i64,
+    pub per_server: i64,
+    pub per_channel: i64,
+}
+
+impl Quotas {
+    pub fn for_server(config: &Config, settings: &ServerSettings) -> Self {
+        Self {
+            per_user: settings.max_streams_per_user.unwrap_or(config.max_streams_per_user),
+            per_server: settings.max_streams_per_server.unwrap_or(config.max_streams_per_server),
+            per_channel: settings.max_streams_per_channel.unwrap_or(config.max_streams_per_channel),
+        }
+    }
+
+    /// Whether one more stream added by the counted user to the counted
+    /// channel stays within every limit.
+    pub fn check(&self, usage: &Usage) -> Result<(), QuotaExceeded> {
+        if usage.user >= self.per_user {
+            Err(QuotaExceeded::User(self.per_user))
+        } else if usage.server >= self.per_server {
+            Err(QuotaExceeded::Server(self.per_server))
+        } else if usage.channel >= self.per_channel {
+            Err(QuotaExceeded::Channel(self.per_channel))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Streams currently counting against each limit. A Twitch team counts as
+/// one stream, however many members it has.
+#[derive(Debug, Clone, Copy)]
+pub struct Usage {
+    /// Added by one member in this server
+    pub user: i64,
+    pub server: i64,
+    /// Notifying one target channel
+    pub channel: i64,
+}
+
+impl Usage {
+    pub fn load(conn: &Connection, server_id: &str, user_id: &str, channel_id: &str) -> Result<Self, Error> {
+        let user = count_entries(conn, "server_id = ?1 AND user_id = ?2", params![server_id, user_id])?;
+        let server = server_usage(conn, server_id)?;
+        let channel = channel_usage(conn, channel_id)?;
+
+        Ok(Self { user, server, channel })
+    }
+}
+
+/// Streams and teams counting toward a server's limit.
+pub fn server_usage(conn: &Connection, server_id: &str) -> Result<i64, Error> {
+    count_entries(conn, "server_id = ?1", params![server_id])
+}
+
+/// Streams and teams notifying one target channel.
+pub fn channel_usage(conn: &Connection, channel_id: &str) -> Result<i64, Error> {
+    count_entries(conn, "added_in_channel = ?1", params![channel_id])
+}
+
+// `filter` applies to both tables, team members are left out of `streams`
+fn count_entries(conn: &Connection, filter: &str, params: &[&dyn rusqlite::ToSql]) -> Result<i64, Error> {
+    let streams: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM streams WHERE {} AND team_id IS NULL", filter),
+        params,
+        |row| row.get(0),
+    )?;
+    let teams: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM teams WHERE {}", filter),
+        params,
+        |row| row.get(0),
+    )?;
+    Ok(streams + teams)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QuotaExceeded {
+    User(i64),
+    Server(i64),
+    Channel(i64),
+}
+
+impl QuotaExceeded {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            QuotaExceeded::User(max) => t!(locale, "quota-user-reached", max = *max),
+            QuotaExceeded::Server(max) => t!(locale, "quota-server-reached", max = *max),
+            QuotaExceeded::Channel(max) => t!(locale, "quota-channel-reached", max = *max),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::config::DEFAULT_PREFIX;
+use crate::error::Error;
+use crate::i18n::Locale;
+use crate::notify::QuietHours;
+use crate::notify::template::MessageMode;
+use rusqlite::{Connection, OptionalExtension, ToSql, params};
+
+/// Per-server overrides. `None` means the global default from `Config` applies.
+#[derive(Debug, Clone, Default)]
+pub struct ServerSettings {
+    pub flap_cooldown_minutes: Option<i64>,
+    pub quiet_start: Option<String>,
+    pub quiet_end: Option<String>,
+    pub quiet_timezone: Option<String>,
+    pub quiet_mode: Option<String>,
+    pub default_template: Option<String>,
+    pub default_mention_role: Option<String>,
+    pub message_mode: Option<String>,
+    pub default_channel: Option<String>,
+    pub language: Option<String>,
+    pub manager_role: Option<String>,
+    /// Quota overrides, only settable by the bot operator
+    pub max_streams_per_user: Option<i64>,
+    pub max_streams_per_server: Option<i64>,
+    pub max_streams_per_channel: Option<i64>,
+    pub prefix: Option<String>,
+}
+
+impl ServerSettings {
+    pub fn quiet_hours(&self) -> Option<QuietHours> {
+        QuietHours::parse(
+            self.quiet_start.as_deref()?,
+            self.quiet_end.as_deref()?,
+            self.quiet_timezone.as_deref()?,
+            self.quiet_mode.as_deref()?,
+        )
+        .ok()
+    }
+
+    /// The template a subscription uses: its own message, else the server default.
+    /// `None` means the built-in default template.
+    pub fn template_source<'a>(&'a self, custom_message: Option<&'a str>) -> Option<&'a str> {
+        custom_message.or(self.default_template.as_deref())
+    }
+
+    /// The role to mention for a subscription. An empty override means
+    /// "no mention" and is not replaced by the server default.
+    pub fn mention_role(&self, subscription_role: Option<&str>) -> Option<String> {
+        subscription_role
+            .or(self.default_mention_role.as_deref())
+            .filter(|role| !role.is_empty())
+            .map(|role| role.to_string())
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.language
+            .as_deref()
+            .and_then(Locale::parse)
+            .unwrap_or_default()
+    }
+
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_deref().unwrap_or(DEFAULT_PREFIX)
+    }
+
+    pub fn message_mode(&self, subscription_mode: Option<&str>) -> MessageMode {
+        subscription_mode
+            .or(self.message_mode.as_deref())
+            .and_then(MessageMode::parse)
+            .unwrap_or(MessageMode::Text)
+    }
+}
+
+pub fn load_server_settings(conn: &Connection, server_id: &str) -> Result<ServerSettings, Error> {
+    let settings = conn
+        .query_row(
+            "SELECT flap_cooldown_minutes, quiet_start, quiet_end, quiet_timezone, quiet_mode,
+                    default_template, default_mention_role, message_mode, default_channel, language,
+                    manager_role, max_streams_per_user, max_streams_per_server, max_streams_per_channel,
+                    prefix
+             FROM server_settings WHERE server_id = ?1",
+            params![server_id],
+            |row| {
+                Ok(ServerSettings {
+                    flap_cooldown_minutes: row.get(0)?,
+                    quiet_start: row.get(1)?,
+                    quiet_end: row.get(2)?,
+                    quiet_timezone: row.get(3)?,
+                    quiet_mode: row.get(4)?,
+                    default_template: row.get(5)?,
+                    default_mention_role: row.get(6)?,
+                    message_mode: row.get(7)?,
+                    default_channel: row.get(8)?,
+                    language: row.get(9)?,
+                    manager_role: row.get(10)?,
+                    max_streams_per_user: row.get(11)?,
+                    max_streams_per_server: row.get(12)?,
+                    max_streams_per_channel: row.get(13)?,
+                    prefix: row.get(14)?,
+                })
+            },
+        )
+        .optional()?;
+
+    Ok(settings.unwrap_or_default())
+}
+
+/// Sets a single column of a server's settings row, creating the row if needed.
+/// `column` is always one of the fixed names above, never user input.
+pub fn set_server_setting(
+    conn: &Connection,
+    server_id: &str,
+    column: &'static str,
+    value: &dyn ToSql,
+) -> Result<(), Error> {
+    conn.execute(
+        &format!(
+            "INSERT INTO server_settings (server_id, {column}) VALUES (?1, ?2)
+             ON CONFLICT(server_id) DO UPDATE SET {column} = excluded.{column}"
+        ),
+        params![server_id, value],
+    )?;
+
+    Ok(())
+}
@@ -1,17 +1,37 @@
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::sync::Notify;
 use parking_lot::RwLock;
+use chrono::{DateTime, Utc};
 use crate::config::Config;
 
+/// EventSub connection health, shown by `!streambotstatus`
+#[derive(Debug, Clone, Default)]
+pub struct EventSubStatus {
+    pub connected: bool,
+    /// When the current session's welcome message arrived
+    pub session_started: Option<DateTime<Utc>>,
+    pub last_keepalive: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone)]
 pub struct State {
     pub db: Pool<SqliteConnectionManager>,
-    #[allow(dead_code)] // Used in background EventSub task
     pub online_channels: Arc<RwLock<HashSet<String>>>,
+    /// When each channel last went offline, used to suppress flapping re-announcements
+    pub offline_since: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
     pub client_id: String,
     pub session_id: Arc<RwLock<Option<String>>>,
     pub twitch_token: String,
+    /// Bot token, for Stoat requests the client library doesn't cover
+    pub stoat_token: String,
     pub config: Config,
+    /// Wakes the outbox worker when a notification is queued
+    pub outbox_notify: Arc<Notify>,
+    /// Command prefix per server ID, filled as servers send messages
+    pub prefixes: Arc<RwLock<HashMap<String, String>>>,
+    pub eventsub: Arc<RwLock<EventSubStatus>>,
+    pub started_at: DateTime<Utc>,
 }
@@ -0,0 +1,386 @@
+use crate::config::{
+    CATEGORY_POLL_MAX_PAGES, CATEGORY_POLL_SECS, CATEGORY_SEEN_RETENTION_HOURS, MAX_API_RETRIES,
+    RATE_LIMIT_DELAY_MS,
+};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::QuietMode;
+use crate::notify::outbox;
+use crate::notify::quiet_hours::{queue_notification, strip_role_mentions};
+use crate::notify::template::escape_markdown;
+use crate::settings::load_server_settings;
+use crate::state::State;
+use crate::twitch::types::{HelixGame, HelixStream};
+use crate::twitch::validation::retry_with_backoff;
+use chrono::Utc;
+use rusqlite::{Connection, params};
+use std::collections::HashMap;
+
+/// A Twitch category followed by a server.
+#[derive(Debug, Clone)]
+pub struct Category {
+    pub id: i64,
+    pub server_id: String,
+    pub game_id: String,
+    pub game_name: String,
+    pub added_in_channel: String,
+    pub user_id: String,
+    pub min_viewers: u64,
+    /// Two-letter stream language, any language if unset
+    pub language: Option<String>,
+    /// Whether the streams live when it was added have been recorded yet
+    pub primed: bool,
+}
+
+/// Whether a language filter is one Helix Get Streams accepts: an ISO 639-1
+/// code or `other`.
+pub fn is_valid_language(language: &str) -> bool {
+    language == "other" || (language.len() == 2 && language.chars().all(|c| c.is_ascii_lowercase()))
+}
+
+// Game names contain spaces and punctuation, unlike logins
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Looks up a category by its exact name, or by ID if `input` is numeric.
+/// Returns `Ok(None)` if there is no such category.
+pub async fn get_game(input: &str, twitch_token: &str, client_id: &str) -> Result<Option<HelixGame>, String> {
+    let http_client = reqwest::Client::new();
+    let query = if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        format!("id={}", input)
+    } else {
+        format!("name={}", encode_query_value(input))
+    };
+    let url = format!("https://api.twitch.tv/helix/games?{}", query);
+
+    let resp = retry_with_backoff(
+        || {
+            let http_client = http_client.clone();
+            let url = url.clone();
+            let twitch_token = twitch_token.to_string();
+            let client_id = client_id.to_string();
+            async move {
+                http_client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", twitch_token))
+                    .header("Client-Id", &client_id)
+                    .send()
+                    .await
+            }
+        },
+        MAX_API_RETRIES,
+    )
+    .await
+    .map_err(|e| format!("Request error: {:?}", e))?;
+
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    match data["data"].get(0) {
+        Some(game) => serde_json::from_value(game.clone())
+            .map(Some)
+            .map_err(|e| format!("Parse error: {}", e)),
+        None => Ok(None),
+    }
+}
+
+/// Fetches the live streams in a category with at least `min_viewers`,
+/// most viewed first. Helix sorts by viewers, so paging stops at the first
+/// stream below the threshold or after `CATEGORY_POLL_MAX_PAGES` pages.
+pub async fn get_category_streams(
+    game_id: &str,
+    language: Option<&str>,
+    min_viewers: u64,
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<Vec<HelixStream>, String> {
+    let http_client = reqwest::Client::new();
+    let mut streams = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    for _ in 0..CATEGORY_POLL_MAX_PAGES {
+        let mut url = format!("https://api.twitch.tv/helix/streams?first=100&game_id={}", game_id);
+        if let Some(language) = language {
+            url.push_str(&format!("&language={}", language));
+        }
+        if let Some(cursor) = &cursor {
+            url.push_str(&format!("&after={}", encode_query_value(cursor)));
+        }
+
+        let resp = retry_with_backoff(
+            || {
+                let http_client = http_client.clone();
+                let url = url.clone();
+                let twitch_token = twitch_token.to_string();
+                let client_id = client_id.to_string();
+                async move {
+                    http_client
+                        .get(&url)
+                        .header("Authorization", format!("Bearer {}", twitch_token))
+                        .header("Client-Id", &client_id)
+                        .send()
+                        .await
+                }
+            },
+            MAX_API_RETRIES,
+        )
+        .await
+        .map_err(|e| format!("Request error: {:?}", e))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Parse error: {}", e))?;
+        let batch: Vec<HelixStream> = serde_json::from_value(data["data"].clone())
+            .map_err(|e| format!("Parse error: {}", e))?;
+
+        let reached_threshold = batch.iter().any(|stream| stream.viewer_count < min_viewers);
+        streams.extend(batch.into_iter().filter(|stream| stream.viewer_count >= min_viewers));
+        cursor = data["pagination"]["cursor"].as_str().map(str::to_string);
+        if reached_threshold || cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(streams)
+}
+
+pub fn load_categories(conn: &Connection, server_id: Option<&str>) -> Result<Vec<Category>, Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, server_id, game_id, game_name, added_in_channel, user_id, min_viewers, language, primed FROM categories
+         WHERE ?1 IS NULL OR server_id = ?1 ORDER BY id",
+    )?;
+    let categories = stmt
+        .query_map(params![server_id], |row| {
+            Ok(Category {
+                id: row.get(0)?,
+                server_id: row.get(1)?,
+                game_id: row.get(2)?,
+                game_name: row.get(3)?,
+                added_in_channel: row.get(4)?,
+                user_id: row.get(5)?,
+                min_viewers: row.get::<_, i64>(6)?.max(0) as u64,
+                language: row.get(7)?,
+                primed: row.get(8)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(categories)
+}
+
+pub fn start_category_poll_task(state: State) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(CATEGORY_POLL_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = poll_categories(&state).await {
+                eprintln!("✗ Failed to poll Twitch categories: {}", e);
+            }
+        }
+    })
+}
+
+// Records the streams as seen and returns the ones that weren't before
+fn record_seen(conn: &mut Connection, category: &Category, streams: Vec<HelixStream>) -> Result<Vec<HelixStream>, Error> {
+    let tx = conn.transaction()?;
+    let now = Utc::now();
+    let mut new_streams = Vec::new();
+    for stream in streams {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO category_seen (category_id, stream_id, seen_at) VALUES (?1, ?2, ?3)",
+            params![category.id, stream.id, now.to_rfc3339()],
+        )?;
+        if inserted > 0 {
+            new_streams.push(stream);
+        } else {
+            tx.execute(
+                "UPDATE category_seen SET seen_at = ?1 WHERE category_id = ?2 AND stream_id = ?3",
+                params![now.to_rfc3339(), category.id, stream.id],
+            )?;
+        }
+    }
+    tx.execute(
+        "DELETE FROM category_seen WHERE category_id = ?1 AND seen_at < ?2",
+        params![
+            category.id,
+            (now - chrono::Duration::hours(CATEGORY_SEEN_RETENTION_HOURS)).to_rfc3339()
+        ],
+    )?;
+    if !category.primed {
+        tx.execute("UPDATE categories SET primed = 1 WHERE id = ?1", params![category.id])?;
+    }
+    tx.commit()?;
+    Ok(new_streams)
+}
+
+/// The message posted for a category's new streams, `more` being the ones
+/// left out by the server's cap.
+fn announcement(locale: Locale, category: &Category, streams: &[HelixStream], more: usize) -> String {
+    let mut message = format!(
+        "**{}**\n",
+        t!(
+            locale,
+            "category-new-streams",
+            game = escape_markdown(&category.game_name),
+            count = streams.len() + more
+        )
+    );
+    for stream in streams {
+        message.push_str(&format!(
+            "🔴 **[{}](https://twitch.tv/{})** - {} · {}\n",
+            escape_markdown(&stream.user_name),
+            stream.user_login,
+            escape_markdown(&stream.title),
+            t!(locale, "embed-viewers", count = stream.viewer_count)
+        ));
+    }
+    if more > 0 {
+        message.push_str(&t!(locale, "live-more", count = more));
+    }
+    message
+}
+
+async fn poll_categories(state: &State) -> Result<(), Error> {
+    let db = state.db.clone();
+    let categories = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_categories(&conn, None)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    // Servers following the same category and language share one lookup,
+    // made with the lowest threshold among them
+    let mut lookups: HashMap<(String, Option<String>), u64> = HashMap::new();
+    for category in &categories {
+        let key = (category.game_id.clone(), category.language.clone());
+        let min_viewers = lookups.entry(key).or_insert(category.min_viewers);
+        *min_viewers = (*min_viewers).min(category.min_viewers);
+    }
+
+    let mut found: HashMap<(String, Option<String>), Vec<HelixStream>> = HashMap::new();
+    for ((game_id, language), min_viewers) in lookups {
+        match get_category_streams(
+            &game_id,
+            language.as_deref(),
+            min_viewers,
+            &state.twitch_token,
+            &state.client_id,
+        )
+        .await
+        {
+            Ok(streams) => {
+                found.insert((game_id, language), streams);
+            }
+            Err(e) => eprintln!("✗ Failed to fetch streams for category {}: {}", game_id, e),
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(RATE_LIMIT_DELAY_MS)).await;
+    }
+
+    // Streams each server may still get announced in this poll
+    let mut remaining: HashMap<String, usize> = HashMap::new();
+    for category in categories {
+        let key = (category.game_id.clone(), category.language.clone());
+        let Some(streams) = found.get(&key) else {
+            continue;
+        };
+        let streams: Vec<HelixStream> = streams
+            .iter()
+            .filter(|stream| stream.viewer_count >= category.min_viewers)
+            .cloned()
+            .collect();
+
+        let db = state.db.clone();
+        let category_clone = category.clone();
+        let seen = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+            let mut conn = db.get()?;
+            let new_streams = record_seen(&mut conn, &category_clone, streams)?;
+            let settings = load_server_settings(&conn, &category_clone.server_id)?;
+            Ok((new_streams, settings))
+        })
+        .await
+        .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))
+        .and_then(|seen| seen);
+        let (new_streams, settings) = match seen {
+            Ok(seen) => seen,
+            Err(e) => {
+                eprintln!("✗ Failed to record streams in category {}: {}", category.game_name, e);
+                continue;
+            }
+        };
+
+        // Streams already live when the category was added aren't news
+        if !category.primed || new_streams.is_empty() {
+            continue;
+        }
+
+        let left = remaining
+            .entry(category.server_id.clone())
+            .or_insert(state.config.max_category_streams_per_poll);
+        let shown = new_streams.len().min(*left);
+        if shown == 0 {
+            continue;
+        }
+        *left -= shown;
+        let more = new_streams.len() - shown;
+
+        println!(
+            "✓ {} new streams in category {} for server {}",
+            new_streams.len(),
+            category.game_name,
+            category.server_id
+        );
+
+        let mut message = announcement(settings.locale(), &category, &new_streams[..shown], more);
+
+        if let Some(quiet_hours) = settings.quiet_hours()
+            && quiet_hours.is_active(Utc::now())
+        {
+            match quiet_hours.mode {
+                QuietMode::Drop => {
+                    println!("ℹ Quiet hours, dropped category {} for {}", category.game_name, category.added_in_channel);
+                    continue;
+                }
+                QuietMode::Queue => {
+                    for stream in &new_streams[..shown] {
+                        if let Err(e) = queue_notification(
+                            state,
+                            &category.server_id,
+                            &category.added_in_channel,
+                            &stream.user_login,
+                            &stream.user_name,
+                        )
+                        .await
+                        {
+                            eprintln!("Failed to queue notification: {e}");
+                        }
+                    }
+                    continue;
+                }
+                // Stream titles are posted verbatim and may carry mentions
+                QuietMode::Silent => message = strip_role_mentions(&message),
+            }
+        }
+
+        // Keyed by the first new stream, so a retry after a crash is not posted twice
+        let idempotency_key = format!("category:{}:{}", category.id, new_streams[0].id);
+        if let Err(e) = outbox::enqueue(state, idempotency_key, category.added_in_channel.clone(), message, None).await {
+            eprintln!("✗ Failed to queue announcement for category {}: {}", category.game_name, e);
+        }
+    }
+
+    Ok(())
+}
@@ -1,31 +1,40 @@
-use crate::config::RECONNECT_DELAY_SECS;
+use crate::config::{MAX_FLAP_COOLDOWN_MINUTES, RECONNECT_DELAY_SECS};
 use crate::error::Error;
+use crate::notify::quiet_hours::{queue_notification, strip_role_mentions};
+use crate::notify::QuietMode;
+use crate::notify::{filter, outbox};
+use crate::notify::template::{MessageMode, Template, TemplateContext};
+use crate::settings::{ServerSettings, load_server_settings};
+use crate::state::State;
+use crate::twitch::streams::get_stream;
 use crate::twitch::subscription::subscribe_to_channels;
 use crate::twitch::types::{
     EventSubMessage, SessionReconnect, SessionWelcome, StreamOffline, StreamOnline,
 };
 
 use futures_util::StreamExt;
-use parking_lot::RwLock;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
 use serde_json::Value;
-use std::collections::HashSet;
-use std::sync::Arc;
 use tokio::sync::broadcast;
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
 
-pub fn start_eventsub_task(
-    stoat_token: String,
-    twitch_bot_token: String,
-    twitch_client_id: String,
-    db: Pool<SqliteConnectionManager>,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-    session_id: Arc<RwLock<Option<String>>>,
-) -> tokio::task::JoinHandle<()> {
+/// A Stoat channel that should be notified when a Twitch channel goes live.
+#[derive(Debug, Clone)]
+struct AlertTarget {
+    channel_id: String,
+    server_id: Option<String>,
+    custom_message: Option<String>,
+    mention_role: Option<String>,
+    message_mode: Option<String>,
+    game_filter: Option<String>,
+    title_filter: Option<String>,
+    settings: ServerSettings,
+}
+
+pub fn start_eventsub_task(state: State) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        let http_client = reqwest::Client::new();
         let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(1);
 
         spawn_signal_handler(shutdown_tx.clone());
@@ -38,23 +47,18 @@ pub fn start_eventsub_task(
                 result = connect_async(&ws_url) => {
                     match result {
                         Ok((ws_stream, _)) => {
-                            online_channels.write().clear();
+                            state.online_channels.write().clear();
 
                             match run_connection(
                                 ws_stream,
-                                &stoat_token,
-                                &twitch_bot_token,
-                                &twitch_client_id,
-                                db.clone(),
-                                online_channels.clone(),
-                                session_id.clone(),
-                                http_client.clone(),
+                                &state,
                                 shutdown_tx.subscribe(),
                             ).await {
                                 Ok(Some(new_url)) => ws_url = new_url,
                                 Ok(None) => {}
                                 Err(e) => eprintln!("EventSub error: {e}"),
                             }
+                            state.eventsub.write().connected = false;
                         }
                         Err(e) => eprintln!("Connection failed: {e}"),
                     }
@@ -82,13 +86,7 @@ async fn run_connection(
     ws_stream: tokio_tungstenite::WebSocketStream<
         tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
     >,
-    stoat_token: &str,
-    twitch_bot_token: &str,
-    twitch_client_id: &str,
-    db: Pool<SqliteConnectionManager>,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-    session_id: Arc<RwLock<Option<String>>>,
-    http_client: reqwest::Client,
+    state: &State,
     mut shutdown: broadcast::Receiver<()>,
 ) -> Result<Option<String>, Error> {
     let (_write, mut read) = ws_stream.split();
@@ -103,16 +101,7 @@ async fn run_connection(
                     None => return Ok(None),
                 };
 
-                if let Some(new_url) = handle_ws_message(
-                    msg,
-                    stoat_token,
-                    twitch_bot_token,
-                    twitch_client_id,
-                    db.clone(),
-                    online_channels.clone(),
-                    session_id.clone(),
-                    http_client.clone(),
-                ).await? {
+                if let Some(new_url) = handle_ws_message(msg, state).await? {
                     return Ok(Some(new_url));
                 }
             }
@@ -120,16 +109,7 @@ async fn run_connection(
     }
 }
 
-async fn handle_ws_message(
-    msg: WsMessage,
-    stoat_token: &str,
-    twitch_bot_token: &str,
-    twitch_client_id: &str,
-    db: Pool<SqliteConnectionManager>,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-    session_id: Arc<RwLock<Option<String>>>,
-    http_client: reqwest::Client,
-) -> Result<Option<String>, Error> {
+async fn handle_ws_message(msg: WsMessage, state: &State) -> Result<Option<String>, Error> {
     let text = match msg {
         WsMessage::Text(t) => t,
         WsMessage::Close(_) => return Ok(None),
@@ -142,24 +122,13 @@ async fn handle_ws_message(
 
     match event_msg.metadata.message_type.as_str() {
         "session_welcome" => {
-            handle_welcome(
-                event_msg.payload,
-                twitch_bot_token,
-                twitch_client_id,
-                db,
-                session_id,
-            )
-            .await?;
+            handle_welcome(event_msg.payload, state).await?;
         }
         "notification" => {
-            handle_notification(
-                event_msg.payload,
-                stoat_token,
-                db,
-                online_channels,
-                http_client,
-            )
-            .await?;
+            handle_notification(event_msg.payload, state).await?;
+        }
+        "session_keepalive" => {
+            state.eventsub.write().last_keepalive = Some(chrono::Utc::now());
         }
         "session_reconnect" => {
             return handle_reconnect(event_msg.payload);
@@ -170,31 +139,26 @@ async fn handle_ws_message(
     Ok(None)
 }
 
-async fn handle_welcome(
-    payload: Value,
-    twitch_bot_token: &str,
-    twitch_client_id: &str,
-    db: Pool<SqliteConnectionManager>,
-    session_id: Arc<RwLock<Option<String>>>,
-) -> Result<(), Error> {
+async fn handle_welcome(payload: Value, state: &State) -> Result<(), Error> {
     let welcome: SessionWelcome =
         serde_json::from_value(payload).map_err(|e| Error::DatabaseError(e.to_string()))?;
 
     let id = welcome.session.id;
-    *session_id.write() = Some(id.clone());
+    *state.session_id.write() = Some(id.clone());
+    {
+        let now = chrono::Utc::now();
+        let mut status = state.eventsub.write();
+        status.connected = true;
+        status.session_started = Some(now);
+        status.last_keepalive = Some(now);
+    }
 
-    subscribe_to_channels(&id, twitch_bot_token, twitch_client_id, db).await;
+    subscribe_to_channels(&id, &state.twitch_token, &state.client_id, state.db.clone()).await;
 
     Ok(())
 }
 
-async fn handle_notification(
-    payload: Value,
-    stoat_token: &str,
-    db: Pool<SqliteConnectionManager>,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-    http_client: reqwest::Client,
-) -> Result<(), Error> {
+async fn handle_notification(payload: Value, state: &State) -> Result<(), Error> {
     let event_data = payload
         .get("event")
         .cloned()
@@ -208,10 +172,10 @@ async fn handle_notification(
 
     match event_type {
         "stream.online" => {
-            handle_stream_online(event_data, stoat_token, db, online_channels, http_client).await?;
+            handle_stream_online(event_data, state).await?;
         }
         "stream.offline" => {
-            handle_stream_offline(event_data, online_channels).await?;
+            handle_stream_offline(event_data, state).await?;
         }
         _ => {}
     }
@@ -219,20 +183,14 @@ async fn handle_notification(
     Ok(())
 }
 
-async fn handle_stream_online(
-    event_data: Value,
-    stoat_token: &str,
-    db: Pool<SqliteConnectionManager>,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-    http_client: reqwest::Client,
-) -> Result<(), Error> {
+async fn handle_stream_online(event_data: Value, state: &State) -> Result<(), Error> {
     let event: StreamOnline =
         serde_json::from_value(event_data).map_err(|e| Error::DatabaseError(e.to_string()))?;
 
     let channel = event.broadcaster_user_login.to_lowercase();
 
     let should_notify = {
-        let mut set = online_channels.write();
+        let mut set = state.online_channels.write();
         set.insert(channel.clone())
     };
 
@@ -240,54 +198,131 @@ async fn handle_stream_online(
         return Ok(());
     }
 
-    let alert_channels = get_alert_channels(db, channel.clone()).await;
-
-    for (alert_channel, custom_message) in alert_channels {
-        let message = if let Some(custom_msg) = custom_message {
-            // Use custom message - replace {channel} and {url} placeholders
-            custom_msg
-                .replace("{channel}", &event.broadcaster_user_name)
-                .replace(
-                    "{url}",
-                    &format!("https://twitch.tv/{}", event.broadcaster_user_login),
-                )
-        } else {
-            // Use default message
-            format!(
-                "{} is now live! https://twitch.tv/{}",
-                event.broadcaster_user_name, event.broadcaster_user_login
-            )
+    let offline_at = state.offline_since.write().remove(&channel);
+    let alert_targets = get_alert_targets(state.db.clone(), channel.clone()).await;
+    if alert_targets.is_empty() {
+        return Ok(());
+    }
+
+    // Title, game and viewers aren't part of the EventSub event
+    let stream = match get_stream(
+        &event.broadcaster_user_id,
+        &state.twitch_token,
+        &state.client_id,
+    )
+    .await
+    {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("✗ Failed to fetch stream details for {}: {}", channel, e);
+            None
+        }
+    };
+    let mut base_context = TemplateContext::new(
+        &event.broadcaster_user_login,
+        &event.broadcaster_user_name,
+        stream.as_ref(),
+    );
+    base_context.started_at = base_context.started_at.or(event.started_at);
+
+    for target in alert_targets {
+        if !filter::matches(
+            target.game_filter.as_deref(),
+            target.title_filter.as_deref(),
+            stream.as_ref(),
+        ) {
+            println!("ℹ {} doesn't match filters for {}, skipping", channel, target.channel_id);
+            continue;
+        }
+
+        if let Some(offline_at) = offline_at {
+            let cooldown = target
+                .settings
+                .flap_cooldown_minutes
+                .unwrap_or(state.config.flap_cooldown_minutes);
+
+            if chrono::Utc::now() - offline_at < chrono::Duration::minutes(cooldown) {
+                println!(
+                    "ℹ {} came back within {} min cooldown, skipping {}",
+                    channel, cooldown, target.channel_id
+                );
+                continue;
+            }
+        }
+
+        let locale = target.settings.locale();
+        let template = match target
+            .settings
+            .template_source(target.custom_message.as_deref())
+            .map(Template::parse)
+        {
+            Some(Ok(template)) => template,
+            Some(Err(e)) => {
+                eprintln!("✗ Invalid template for {} in {}: {}", channel, target.channel_id, e);
+                Template::default_template(locale)
+            }
+            None => Template::default_template(locale),
+        };
+        let context = TemplateContext {
+            mention_role: target.settings.mention_role(target.mention_role.as_deref()),
+            ..base_context.clone()
+        };
+        let mut message = template.render(&context);
+        let embed = match target.settings.message_mode(target.message_mode.as_deref()) {
+            MessageMode::Embed => Some(context.embed(locale)),
+            MessageMode::Text => None,
         };
 
-        let payload = serde_json::json!({ "content": message });
-        let url = format!(
-            "https://api.revolt.chat/channels/{}/messages",
-            alert_channel
-        );
-
-        if let Err(e) = http_client
-            .post(&url)
-            .header("x-bot-token", stoat_token)
-            .json(&payload)
-            .send()
-            .await
+        if let Some(quiet_hours) = target.settings.quiet_hours()
+            && quiet_hours.is_active(chrono::Utc::now())
         {
-            eprintln!("Failed to send notification: {e}");
+            match quiet_hours.mode {
+                QuietMode::Drop => {
+                    println!("ℹ Quiet hours, dropped {} for {}", channel, target.channel_id);
+                    continue;
+                }
+                QuietMode::Queue => {
+                    if let Some(server_id) = &target.server_id
+                        && let Err(e) = queue_notification(
+                            state,
+                            server_id,
+                            &target.channel_id,
+                            &event.broadcaster_user_login,
+                            &event.broadcaster_user_name,
+                        )
+                        .await
+                    {
+                        eprintln!("Failed to queue notification: {e}");
+                    }
+                    continue;
+                }
+                QuietMode::Silent => message = strip_role_mentions(&message),
+            }
+        }
+
+        // One key per stream session and target, so duplicate events never double-post
+        let idempotency_key = format!("{}:{}", event.id, target.channel_id);
+        if let Err(e) =
+            outbox::enqueue(state, idempotency_key, target.channel_id, message, embed).await
+        {
+            eprintln!("Failed to queue notification: {e}");
         }
     }
 
     Ok(())
 }
 
-async fn handle_stream_offline(
-    event_data: Value,
-    online_channels: Arc<RwLock<HashSet<String>>>,
-) -> Result<(), Error> {
+async fn handle_stream_offline(event_data: Value, state: &State) -> Result<(), Error> {
     let event: StreamOffline =
         serde_json::from_value(event_data).map_err(|e| Error::DatabaseError(e.to_string()))?;
 
     let channel = event.broadcaster_user_login.to_lowercase();
-    online_channels.write().remove(&channel);
+    state.online_channels.write().remove(&channel);
+
+    let now = chrono::Utc::now();
+    let mut offline_since = state.offline_since.write();
+    offline_since.retain(|_, at| now - *at < chrono::Duration::minutes(MAX_FLAP_COOLDOWN_MINUTES));
+    offline_since.insert(channel, now);
 
     Ok(())
 }
@@ -299,19 +334,46 @@ fn handle_reconnect(payload: Value) -> Result<Option<String>, Error> {
     Ok(Some(reconnect.session.reconnect_url))
 }
 
-async fn get_alert_channels(
+async fn get_alert_targets(
     db: Pool<SqliteConnectionManager>,
     channel_name: String,
-) -> Vec<(String, Option<String>)> {
+) -> Vec<AlertTarget> {
     tokio::task::spawn_blocking(move || {
         let conn = db.get().ok()?;
         let mut stmt = conn
-            .prepare("SELECT added_in_channel, custom_message FROM streams WHERE channel_name = ?1")
+            .prepare(
+                "SELECT added_in_channel, custom_message, server_id, mention_role, message_mode,
+                        game_filter, title_filter
+                 FROM streams WHERE channel_name = ?1 AND enabled = 1 AND paused = 0",
+            )
             .ok()?;
         let rows = stmt
-            .query_map([&channel_name], |row| Ok((row.get(0)?, row.get(1)?)))
+            .query_map([&channel_name], |row| {
+                Ok(AlertTarget {
+                    channel_id: row.get(0)?,
+                    custom_message: row.get(1)?,
+                    server_id: row.get(2)?,
+                    mention_role: row.get(3)?,
+                    message_mode: row.get(4)?,
+                    game_filter: row.get(5)?,
+                    title_filter: row.get(6)?,
+                    settings: ServerSettings::default(),
+                })
+            })
             .ok()?;
-        Some(rows.filter_map(|r| r.ok()).collect())
+
+        let targets = rows
+            .filter_map(|r| r.ok())
+            .map(|mut target| {
+                target.settings = target
+                    .server_id
+                    .as_ref()
+                    .and_then(|id| load_server_settings(&conn, id).ok())
+                    .unwrap_or_default();
+                target
+            })
+            .collect();
+        Some(targets)
     })
     .await
     .ok()
@@ -1,8 +1,13 @@
+pub mod categories;
 pub mod eventsub;
+pub mod streams;
 pub mod subscription;
+pub mod teams;
 pub mod types;
 pub mod validation;
 
+pub use categories::start_category_poll_task;
 pub use eventsub::start_eventsub_task;
-pub use subscription::{subscribe_single_channel, unsubscribe_single_channel};
+pub use subscription::{subscribe_broadcaster, subscribe_single_channel, unsubscribe_single_channel};
+pub use teams::start_team_sync_task;
 pub use validation::validate_twitch_channel;
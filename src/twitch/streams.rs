@@ -0,0 +1,96 @@
+use crate::config::{HELIX_STREAMS_PER_REQUEST, MAX_API_RETRIES};
+use crate::twitch::types::HelixStream;
+use crate::twitch::validation::retry_with_backoff;
+
+/// Fetches a live stream's current details from Helix Get Streams.
+/// Returns `Ok(None)` if the broadcaster is offline.
+pub async fn get_stream(
+    broadcaster_id: &str,
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<Option<HelixStream>, String> {
+    let http_client = reqwest::Client::new();
+    let url = format!(
+        "https://api.twitch.tv/helix/streams?user_id={}",
+        broadcaster_id
+    );
+
+    let resp = retry_with_backoff(
+        || {
+            let http_client = http_client.clone();
+            let url = url.clone();
+            let twitch_token = twitch_token.to_string();
+            let client_id = client_id.to_string();
+            async move {
+                http_client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", twitch_token))
+                    .header("Client-Id", &client_id)
+                    .send()
+                    .await
+            }
+        },
+        MAX_API_RETRIES,
+    )
+    .await
+    .map_err(|e| format!("Request error: {:?}", e))?;
+
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    match data["data"].get(0) {
+        Some(stream) => serde_json::from_value(stream.clone())
+            .map(Some)
+            .map_err(|e| format!("Parse error: {}", e)),
+        None => Ok(None),
+    }
+}
+
+/// Fetches the details of every live stream among `logins`, 100 per Helix
+/// request. Offline channels are simply missing from the result.
+pub async fn get_streams(
+    logins: &[String],
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<Vec<HelixStream>, String> {
+    let http_client = reqwest::Client::new();
+    let mut streams = Vec::new();
+
+    for chunk in logins.chunks(HELIX_STREAMS_PER_REQUEST) {
+        // Logins are validated ASCII, so they need no escaping
+        let query: Vec<String> = chunk.iter().map(|login| format!("user_login={}", login)).collect();
+        let url = format!("https://api.twitch.tv/helix/streams?first=100&{}", query.join("&"));
+
+        let resp = retry_with_backoff(
+            || {
+                let http_client = http_client.clone();
+                let url = url.clone();
+                let twitch_token = twitch_token.to_string();
+                let client_id = client_id.to_string();
+                async move {
+                    http_client
+                        .get(&url)
+                        .header("Authorization", format!("Bearer {}", twitch_token))
+                        .header("Client-Id", &client_id)
+                        .send()
+                        .await
+                }
+            },
+            MAX_API_RETRIES,
+        )
+        .await
+        .map_err(|e| format!("Request error: {:?}", e))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Parse error: {}", e))?;
+        let batch: Vec<HelixStream> = serde_json::from_value(data["data"].clone())
+            .map_err(|e| format!("Parse error: {}", e))?;
+        streams.extend(batch);
+    }
+
+    Ok(streams)
+}
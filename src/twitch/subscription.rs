@@ -17,14 +17,30 @@ pub async fn subscribe_single_channel(
         .await
         .map_err(|e| format!("Failed to get broadcaster ID: {}", e))?;
 
-    if is_already_subscribed(&broadcaster_id, twitch_token, client_id, &http_client).await {
+    subscribe_broadcaster(channel, &broadcaster_id, session_id, twitch_token, client_id).await?;
+
+    Ok(broadcaster_id)
+}
+
+/// Subscribes to a channel whose broadcaster ID is already known, skipping
+/// the user lookup `subscribe_single_channel` does.
+pub async fn subscribe_broadcaster(
+    channel: &str,
+    broadcaster_id: &str,
+    session_id: &str,
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<(), String> {
+    let http_client = reqwest::Client::new();
+
+    if is_already_subscribed(broadcaster_id, twitch_token, client_id, &http_client).await {
         println!("  ℹ {} (already subscribed)", channel);
-        return Ok(broadcaster_id);
+        return Ok(());
     }
 
     subscribe_to_event(
         "stream.online",
-        &broadcaster_id,
+        broadcaster_id,
         session_id,
         twitch_token,
         client_id,
@@ -34,16 +50,14 @@ pub async fn subscribe_single_channel(
     .await?;
     subscribe_to_event(
         "stream.offline",
-        &broadcaster_id,
+        broadcaster_id,
         session_id,
         twitch_token,
         client_id,
         &http_client,
         channel,
     )
-    .await?;
-
-    Ok(broadcaster_id)
+    .await
 }
 
 pub async fn unsubscribe_single_channel(channel: &str, twitch_token: &str, client_id: &str) {
@@ -67,10 +81,10 @@ pub async fn unsubscribe_single_channel(channel: &str, twitch_token: &str, clien
     };
 
     for sub in subscriptions {
-        if sub["condition"]["broadcaster_user_id"].as_str() == Some(&broadcaster_id) {
-            if let Some(sub_id) = sub["id"].as_str() {
-                delete_subscription(sub_id, twitch_token, client_id, &http_client).await;
-            }
+        if sub["condition"]["broadcaster_user_id"].as_str() == Some(&broadcaster_id)
+            && let Some(sub_id) = sub["id"].as_str()
+        {
+            delete_subscription(sub_id, twitch_token, client_id, &http_client).await;
         }
     }
 
@@ -228,25 +242,39 @@ async fn subscribe_to_event(
     }
 }
 
-async fn list_subscriptions(
+/// Lists every EventSub subscription of the app, following pagination.
+pub async fn list_subscriptions(
     twitch_token: &str,
     client_id: &str,
     http_client: &reqwest::Client,
 ) -> Result<Vec<serde_json::Value>, String> {
-    let resp = http_client
-        .get("https://api.twitch.tv/helix/eventsub/subscriptions")
-        .header("Authorization", format!("Bearer {}", twitch_token))
-        .header("Client-Id", client_id)
-        .send()
-        .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+    let mut subscriptions = Vec::new();
+    let mut cursor: Option<String> = None;
 
-    let data: serde_json::Value = resp
-        .json()
-        .await
-        .map_err(|e| format!("Parse failed: {}", e))?;
-
-    Ok(data["data"].as_array().cloned().unwrap_or_default())
+    loop {
+        let url = match &cursor {
+            Some(cursor) => format!("https://api.twitch.tv/helix/eventsub/subscriptions?after={}", cursor),
+            None => "https://api.twitch.tv/helix/eventsub/subscriptions".to_string(),
+        };
+        let resp = http_client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", twitch_token))
+            .header("Client-Id", client_id)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Parse failed: {}", e))?;
+
+        subscriptions.extend(data["data"].as_array().cloned().unwrap_or_default());
+        cursor = data["pagination"]["cursor"].as_str().map(str::to_string);
+        if cursor.is_none() {
+            return Ok(subscriptions);
+        }
+    }
 }
 
 async fn delete_subscription(
@@ -298,13 +326,13 @@ async fn remove_channel_from_db(channel: &str, db: Pool<SqliteConnectionManager>
     let channel = channel.to_string();
 
     tokio::task::spawn_blocking(move || {
-        if let Ok(conn) = db.get() {
-            if let Err(e) = conn.execute(
+        if let Ok(conn) = db.get()
+            && let Err(e) = conn.execute(
                 "DELETE FROM streams WHERE channel_name = ?1",
                 params![channel],
-            ) {
-                eprintln!("  ✗ Failed to remove channel from DB: {}", e);
-            }
+            )
+        {
+            eprintln!("  ✗ Failed to remove channel from DB: {}", e);
         }
     })
     .await
@@ -0,0 +1,344 @@
+use crate::config::{MAX_API_RETRIES, MAX_CONCURRENT_SUBSCRIBES, RATE_LIMIT_DELAY_MS, TEAM_SYNC_INTERVAL_SECS};
+use crate::error::Error;
+use crate::i18n::{Locale, t};
+use crate::notify::outbox;
+use crate::notify::template::escape_markdown;
+use crate::settings::load_server_settings;
+use crate::state::State;
+use crate::twitch::types::{HelixTeam, HelixTeamMember};
+use crate::twitch::validation::retry_with_backoff;
+use crate::twitch::{subscribe_broadcaster, unsubscribe_single_channel};
+use futures_util::StreamExt;
+use rusqlite::{Connection, params};
+
+/// A Twitch team followed by a server.
+#[derive(Debug, Clone)]
+pub struct Team {
+    pub id: i64,
+    pub server_id: String,
+    pub team_name: String,
+    pub display_name: String,
+    pub added_in_channel: String,
+    pub user_id: String,
+    /// Member logins as of the last sync
+    pub members: Vec<String>,
+}
+
+/// Membership changes applied by one sync.
+#[derive(Debug, Default)]
+pub struct TeamChanges {
+    pub joined: Vec<String>,
+    pub left: Vec<String>,
+    /// Members who joined but were already tracked in the server on their own
+    pub already_tracked: Vec<String>,
+}
+
+impl TeamChanges {
+    pub fn is_empty(&self) -> bool {
+        self.joined.is_empty() && self.left.is_empty()
+    }
+
+    /// The message posted to the team's channel after a sync.
+    pub fn summary(&self, locale: Locale, team: &str) -> String {
+        let mut summary = format!("**{}**", t!(locale, "team-sync-title", team = escape_markdown(team)));
+        if !self.joined.is_empty() {
+            summary.push('\n');
+            summary.push_str(&t!(locale, "team-sync-joined", members = list_logins(&self.joined)));
+        }
+        if !self.left.is_empty() {
+            summary.push('\n');
+            summary.push_str(&t!(locale, "team-sync-left", members = list_logins(&self.left)));
+        }
+        summary
+    }
+}
+
+pub fn list_logins(logins: &[String]) -> String {
+    logins.iter().map(|login| escape_markdown(login)).collect::<Vec<_>>().join(", ")
+}
+
+/// Normalises a team argument: a team name or a team page URL such as
+/// `https://www.twitch.tv/team/somecrew`. Returns `None` if it names no valid team.
+pub fn parse_team_input(input: &str) -> Option<String> {
+    let input = input.trim().trim_start_matches('<').trim_end_matches('>');
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let name = ["twitch.tv/team/", "www.twitch.tv/team/", "m.twitch.tv/team/"]
+        .iter()
+        .find_map(|prefix| without_scheme.strip_prefix(prefix))
+        .map(|path| path.split(['/', '?', '#']).next().unwrap_or_default())
+        .unwrap_or(without_scheme);
+
+    // Team names are plain ASCII like logins, so they need no escaping in URLs
+    let valid = (1..=64).contains(&name.len()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then(|| name.to_ascii_lowercase())
+}
+
+/// Fetches a team and its members from Helix Get Teams. Returns `Ok(None)`
+/// if no such team exists.
+pub async fn get_team(name: &str, twitch_token: &str, client_id: &str) -> Result<Option<HelixTeam>, String> {
+    let http_client = reqwest::Client::new();
+    let url = format!("https://api.twitch.tv/helix/teams?name={}", name);
+
+    let resp = retry_with_backoff(
+        || {
+            let http_client = http_client.clone();
+            let url = url.clone();
+            let twitch_token = twitch_token.to_string();
+            let client_id = client_id.to_string();
+            async move {
+                http_client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", twitch_token))
+                    .header("Client-Id", &client_id)
+                    .send()
+                    .await
+            }
+        },
+        MAX_API_RETRIES,
+    )
+    .await
+    .map_err(|e| format!("Request error: {:?}", e))?;
+
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    match data["data"].get(0) {
+        Some(team) => serde_json::from_value(team.clone())
+            .map(Some)
+            .map_err(|e| format!("Parse error: {}", e)),
+        None => Ok(None),
+    }
+}
+
+pub fn load_teams(conn: &Connection, server_id: Option<&str>) -> Result<Vec<Team>, Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id, server_id, team_name, display_name, added_in_channel, user_id, members FROM teams
+         WHERE ?1 IS NULL OR server_id = ?1 ORDER BY id",
+    )?;
+    let teams = stmt
+        .query_map(params![server_id], |row| {
+            let members: String = row.get(6)?;
+            Ok(Team {
+                id: row.get(0)?,
+                server_id: row.get(1)?,
+                team_name: row.get(2)?,
+                display_name: row.get(3)?,
+                added_in_channel: row.get(4)?,
+                user_id: row.get(5)?,
+                members: members.split_whitespace().map(str::to_string).collect(),
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(teams)
+}
+
+/// Brings a team's streams in line with its current members on Twitch:
+/// members who joined get a stream in the team's channel, streams of members
+/// who left are removed. Members the server already tracks on their own are
+/// left alone.
+pub async fn sync_team(state: &State, team: &Team, current: &HelixTeam) -> Result<TeamChanges, Error> {
+    let members: Vec<HelixTeamMember> = current.users.clone().unwrap_or_default();
+    let joined: Vec<HelixTeamMember> = members
+        .iter()
+        .filter(|member| !team.members.contains(&member.user_login))
+        .cloned()
+        .collect();
+    let left: Vec<String> = team
+        .members
+        .iter()
+        .filter(|login| !members.iter().any(|member| member.user_login == **login))
+        .cloned()
+        .collect();
+
+    let db = state.db.clone();
+    let team_clone = team.clone();
+    let snapshot: Vec<String> = members.iter().map(|member| member.user_login.clone()).collect();
+    let display_name = current.team_display_name.clone();
+    let joined_clone = joined.clone();
+    let left_clone = left.clone();
+    let (added, already_tracked, unused) = tokio::task::spawn_blocking(move || -> Result<_, Error> {
+        let team = team_clone;
+        let mut conn = db.get()?;
+        let tx = conn.transaction()?;
+        let date = chrono::Utc::now().to_rfc3339();
+
+        let mut added = Vec::new();
+        let mut already_tracked = Vec::new();
+        for member in joined_clone {
+            let tracked: i64 = tx.query_row(
+                "SELECT COUNT(*) FROM streams WHERE server_id = ?1 AND channel_name = ?2",
+                params![team.server_id, member.user_login],
+                |row| row.get(0),
+            )?;
+            if tracked > 0 {
+                already_tracked.push(member.user_login);
+                continue;
+            }
+            tx.execute(
+                "INSERT INTO streams (user_id, channel_name, added_in_channel, date, server_id, team_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![team.user_id, member.user_login, team.added_in_channel, date, team.server_id, team.id],
+            )?;
+            added.push(member);
+        }
+
+        // Channels no other server follows any more
+        let mut unused = Vec::new();
+        for login in left_clone {
+            tx.execute(
+                "DELETE FROM streams WHERE team_id = ?1 AND channel_name = ?2",
+                params![team.id, login],
+            )?;
+            let count: i64 = tx.query_row(
+                "SELECT COUNT(*) FROM streams WHERE channel_name = ?1",
+                params![login],
+                |row| row.get(0),
+            )?;
+            if count == 0 {
+                unused.push(login);
+            }
+        }
+
+        tx.execute(
+            "UPDATE teams SET members = ?1, display_name = ?2 WHERE id = ?3",
+            params![snapshot.join(" "), display_name, team.id],
+        )?;
+        tx.commit()?;
+        Ok((added, already_tracked, unused))
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    let session_id = state.session_id.read().clone();
+    match session_id {
+        Some(session_id) => {
+            futures_util::stream::iter(added)
+                .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |member| {
+                    let session_id = session_id.clone();
+                    async move {
+                        if let Err(e) = subscribe_broadcaster(
+                            &member.user_login,
+                            &member.user_id,
+                            &session_id,
+                            &state.twitch_token,
+                            &state.client_id,
+                        )
+                        .await
+                        {
+                            eprintln!("✗ Failed to subscribe to EventSub for {}: {}", member.user_login, e);
+                        }
+                    }
+                })
+                .await;
+        }
+        None if !added.is_empty() => eprintln!("✗ EventSub session not ready yet"),
+        None => {}
+    }
+
+    futures_util::stream::iter(unused)
+        .for_each_concurrent(MAX_CONCURRENT_SUBSCRIBES, |login| async move {
+            unsubscribe_single_channel(&login, &state.twitch_token, &state.client_id).await;
+        })
+        .await;
+
+    Ok(TeamChanges {
+        joined: joined.into_iter().map(|member| member.user_login).collect(),
+        left,
+        already_tracked,
+    })
+}
+
+pub fn start_team_sync_task(state: State) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        // Streams already in the database are subscribed when EventSub connects,
+        // so the first sync can wait a full period
+        let period = tokio::time::Duration::from_secs(TEAM_SYNC_INTERVAL_SECS);
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = sync_all_teams(&state).await {
+                eprintln!("✗ Failed to sync Twitch teams: {}", e);
+            }
+        }
+    })
+}
+
+async fn sync_all_teams(state: &State) -> Result<(), Error> {
+    let db = state.db.clone();
+    let teams = tokio::task::spawn_blocking(move || {
+        let conn = db.get()?;
+        load_teams(&conn, None)
+    })
+    .await
+    .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))??;
+
+    for team in teams {
+        let current = match get_team(&team.team_name, &state.twitch_token, &state.client_id).await {
+            Ok(Some(current)) => current,
+            // Keep the streams, a team that vanished is more likely a Twitch hiccup than a disbanded team
+            Ok(None) => {
+                eprintln!("✗ Twitch team {} not found, skipping sync", team.team_name);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("✗ Failed to fetch Twitch team {}: {}", team.team_name, e);
+                continue;
+            }
+        };
+
+        let changes = match sync_team(state, &team, &current).await {
+            Ok(changes) => changes,
+            Err(e) => {
+                eprintln!("✗ Failed to sync Twitch team {}: {}", team.team_name, e);
+                continue;
+            }
+        };
+        if !changes.is_empty() {
+            println!(
+                "✓ Synced team {}: {} joined, {} left",
+                team.team_name,
+                changes.joined.len(),
+                changes.left.len()
+            );
+
+            let db = state.db.clone();
+            let server_id = team.server_id.clone();
+            let locale = tokio::task::spawn_blocking(move || -> Result<Locale, Error> {
+                let conn = db.get()?;
+                Ok(load_server_settings(&conn, &server_id)?.locale())
+            })
+            .await
+            .map_err(|e| Error::DatabaseError(format!("Task failed: {}", e)))
+            .and_then(|locale| locale);
+            // The streams are already synced, so still post the summary
+            let locale = locale.unwrap_or_else(|e| {
+                eprintln!("✗ Failed to load settings for server {}: {}", team.server_id, e);
+                Locale::default()
+            });
+
+            let summary = changes.summary(locale, &current.team_display_name);
+            let idempotency_key = format!("team:{}:{}", team.id, chrono::Utc::now().timestamp());
+            if let Err(e) = outbox::enqueue(state, idempotency_key, team.added_in_channel.clone(), summary, None).await {
+                eprintln!("✗ Failed to queue sync summary for team {}: {}", team.team_name, e);
+            }
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(RATE_LIMIT_DELAY_MS)).await;
+    }
+
+    Ok(())
+}
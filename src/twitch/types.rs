@@ -33,10 +33,11 @@ pub struct ReconnectSession {
 
 #[derive(Debug, Deserialize)]
 pub struct StreamOnline {
-    #[allow(dead_code)]
+    pub id: String,
     pub broadcaster_user_id: String,
     pub broadcaster_user_login: String,
     pub broadcaster_user_name: String,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +45,48 @@ pub struct StreamOffline {
     pub broadcaster_user_login: String,
 }
 
+/// An entry from Helix Get Streams
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelixStream {
+    pub id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub game_name: String,
+    pub title: String,
+    pub viewer_count: u64,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// An entry from Helix Get Users
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelixUser {
+    pub id: String,
+    pub login: String,
+}
+
+/// An entry from Helix Get Games
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelixGame {
+    pub id: String,
+    pub name: String,
+}
+
+/// An entry from Helix Get Teams
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelixTeam {
+    pub team_name: String,
+    pub team_display_name: String,
+    /// `null` for a team without members
+    #[serde(default)]
+    pub users: Option<Vec<HelixTeamMember>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelixTeamMember {
+    pub user_id: String,
+    pub user_login: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CreateSubscription {
     #[serde(rename = "type")]
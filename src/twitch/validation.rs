@@ -1,4 +1,5 @@
-use crate::config::RETRY_BASE_DELAY_MS;
+use crate::config::{HELIX_USERS_PER_REQUEST, RETRY_BASE_DELAY_MS};
+use crate::twitch::types::HelixUser;
 use std::future::Future;
 
 pub async fn retry_with_backoff<F, Fut, T, E>(mut f: F, max_retries: u32) -> Result<T, E>
@@ -38,3 +39,143 @@ pub async fn validate_twitch_channel(
     let data: serde_json::Value = resp.json().await?;
     Ok(data["data"][0]["id"].as_str().map(|s| s.to_string()))
 }
+
+/// Twitch paths under `twitch.tv/` that are site pages, not channels.
+const RESERVED_PATHS: &[&str] = &["directory", "downloads", "p", "search", "settings", "videos"];
+
+/// A Twitch channel as typed by a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelInput {
+    Login(String),
+    /// A numeric broadcaster ID, which is resolved to a login via Helix
+    Id(String),
+}
+
+/// Twitch logins are 4 to 25 ASCII letters, digits or underscores.
+pub fn is_valid_login(login: &str) -> bool {
+    (4..=25).contains(&login.len())
+        && login.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Normalises a channel argument: a login, a numeric broadcaster ID, or a
+/// Twitch URL such as `https://www.twitch.tv/somebody/videos` or
+/// `m.twitch.tv/somebody`. Returns `None` if it names no valid channel.
+pub fn parse_channel_input(input: &str) -> Option<ChannelInput> {
+    let input = input.trim().trim_start_matches('<').trim_end_matches('>');
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+
+    let host_end = without_scheme.find('/').unwrap_or(without_scheme.len());
+    let host = without_scheme[..host_end].to_ascii_lowercase();
+    let candidate = if matches!(host.as_str(), "twitch.tv" | "www.twitch.tv" | "m.twitch.tv") {
+        let path = &without_scheme[host_end..];
+        let segment = path
+            .split(['/', '?', '#'])
+            .find(|segment| !segment.is_empty())?;
+        if RESERVED_PATHS.contains(&segment.to_ascii_lowercase().as_str()) {
+            return None;
+        }
+        segment
+    } else if without_scheme.len() != input.len() || host_end != without_scheme.len() {
+        // Some other URL or path
+        return None;
+    } else {
+        input
+    };
+
+    if !candidate.is_empty() && candidate.chars().all(|c| c.is_ascii_digit()) {
+        Some(ChannelInput::Id(candidate.to_string()))
+    } else if is_valid_login(candidate) {
+        Some(ChannelInput::Login(candidate.to_ascii_lowercase()))
+    } else {
+        None
+    }
+}
+
+/// Looks up the login of a broadcaster ID. `Ok(None)` if no such user exists.
+pub async fn get_login_by_id(
+    broadcaster_id: &str,
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<Option<String>, reqwest::Error> {
+    let http_client = reqwest::Client::new();
+    let url = format!("https://api.twitch.tv/helix/users?id={}", broadcaster_id);
+
+    let resp = http_client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", twitch_token))
+        .header("Client-Id", client_id)
+        .send()
+        .await?;
+
+    let data: serde_json::Value = resp.json().await?;
+    Ok(data["data"][0]["login"].as_str().map(|s| s.to_string()))
+}
+
+/// Looks up many users in as few Helix calls as possible, 100 logins or IDs
+/// per request. Users that don't exist are simply missing from the result.
+pub async fn get_users(
+    logins: &[String],
+    ids: &[String],
+    twitch_token: &str,
+    client_id: &str,
+) -> Result<Vec<HelixUser>, String> {
+    let http_client = reqwest::Client::new();
+    let params: Vec<(&str, &String)> = logins
+        .iter()
+        .map(|login| ("login", login))
+        .chain(ids.iter().map(|id| ("id", id)))
+        .collect();
+
+    let mut users = Vec::new();
+    for chunk in params.chunks(HELIX_USERS_PER_REQUEST) {
+        // Logins and IDs are validated ASCII, so they need no escaping
+        let query: Vec<String> = chunk.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+        let url = format!("https://api.twitch.tv/helix/users?{}", query.join("&"));
+
+        let resp = http_client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", twitch_token))
+            .header("Client-Id", client_id)
+            .send()
+            .await
+            .map_err(|e| format!("Request error: {}", e))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Parse error: {}", e))?;
+        let batch: Vec<HelixUser> = serde_json::from_value(data["data"].clone())
+            .map_err(|e| format!("Parse error: {}", e))?;
+        users.extend(batch);
+    }
+
+    Ok(users)
+}
+
+/// Checks the Twitch token with the OAuth validate endpoint and returns the
+/// seconds until it expires, 0 for tokens that don't expire. `Ok(None)` if
+/// Twitch rejects the token.
+pub async fn validate_token(twitch_token: &str) -> Result<Option<i64>, String> {
+    let resp = reqwest::Client::new()
+        .get("https://id.twitch.tv/oauth2/validate")
+        .header("Authorization", format!("OAuth {}", twitch_token))
+        .send()
+        .await
+        .map_err(|e| format!("Request error: {}", e))?;
+
+    if resp.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+    Ok(Some(data["expires_in"].as_i64().unwrap_or(0)))
+}